@@ -0,0 +1,183 @@
+//! Batch mode: every trajectory under an input directory is compressed on a
+//! thread pool and written to the same relative path under the output
+//! directory, with one row per file in a summary CSV.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use trajcomp::evaluation::Report;
+use trajcomp::parallel::parallel_map;
+
+use crate::{
+    compress_tracks, evaluate_tracks, extension, is_std, read_input, write_output, Compressor, InputFormat, IoArgs,
+    OutputFormat,
+};
+
+// Files of the input tree that are never trajectories: GeoLife keeps the
+// transportation modes of a user in `labels.txt` next to `Trajectory/`
+const SKIPPED_FILES: [&str; 1] = ["labels.txt"];
+
+// A row of the summary
+struct FileSummary {
+    file: PathBuf,
+    result: Result<(Report, Duration), String>,
+}
+
+pub(crate) fn run(io: &IoArgs, compressor: Compressor) -> Result<(), Box<dyn Error>> {
+    if is_std(&io.output) {
+        return Err("compressing a directory needs an output directory (--output)".into());
+    }
+    fs::create_dir_all(&io.output).map_err(|e| format!("{}: {}", io.output.display(), e))?;
+    // Never descend into the output, should it be under the input
+    let output_dir = fs::canonicalize(&io.output)?;
+    let extensions: Vec<String> =
+        io.extensions.iter().map(|ext| ext.trim().trim_start_matches('.').to_ascii_lowercase()).collect();
+    let mut files = Vec::new();
+    find_trajectories(&io.input, Path::new(""), &output_dir, &extensions, &mut files)?;
+
+    let outputs = output_stems(&files);
+    let summary = io.summary.clone().unwrap_or_else(|| io.output.join("summary.csv"));
+
+    let now = Instant::now();
+    let jobs: Vec<(&PathBuf, &PathBuf)> = files.iter().zip(&outputs).collect();
+    let rows = parallel_map(&jobs, io.jobs, |&(file, output)| FileSummary {
+        file: file.clone(),
+        result: compress_file(io, compressor, file, output, &summary).map_err(|e| e.to_string()),
+    });
+    let elapsed = now.elapsed();
+
+    write_summary(&summary, &rows).map_err(|e| format!("{}: {}", summary.display(), e))?;
+
+    let mut failed = 0;
+    for row in &rows {
+        if let Err(e) = &row.result {
+            eprintln!("trajcomp: {}", e);
+            failed += 1;
+        }
+    }
+    if !io.quiet {
+        let compressed = rows.len() - failed;
+        eprintln!("{} compressed {} files in {:?}, summary in {}", compressor, compressed, elapsed, summary.display());
+    }
+    if failed > 0 {
+        return Err(format!("{} of {} files failed", failed, rows.len()).into());
+    }
+    Ok(())
+}
+
+// Relative paths of the files with one of `extensions` under `root.join(dir)`,
+// in name order
+fn find_trajectories(
+    root: &Path,
+    dir: &Path,
+    skip: &Path,
+    extensions: &[String],
+    files: &mut Vec<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let path = root.join(dir);
+    let mut entries = fs::read_dir(&path)
+        .and_then(|entries| entries.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let relative = dir.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            if fs::canonicalize(entry.path())? != skip {
+                find_trajectories(root, &relative, skip, extensions, files)?;
+            }
+        } else if extensions.contains(&extension(&relative))
+            && !SKIPPED_FILES.iter().any(|name| entry.file_name().eq_ignore_ascii_case(name))
+        {
+            files.push(relative);
+        }
+    }
+    Ok(())
+}
+
+// Output paths of `files`, without the extension of the output format. A
+// file keeps its own extension when another one in its directory has the
+// same stem, so that `a.plt` and `a.txt` are not both written to `a.csv`.
+fn output_stems(files: &[PathBuf]) -> Vec<PathBuf> {
+    let stem = |file: &PathBuf| file.with_extension("");
+    let mut counts = HashMap::new();
+    for file in files {
+        *counts.entry(stem(file)).or_insert(0) += 1;
+    }
+    files.iter().map(|file| if counts[&stem(file)] > 1 { file.clone() } else { stem(file) }).collect()
+}
+
+// Compresses `io.input/file` into `io.output/output`, with the extension of
+// the output format added. The output must not be the summary.
+fn compress_file(
+    io: &IoArgs,
+    compressor: Compressor,
+    file: &Path,
+    output: &Path,
+    summary: &Path,
+) -> Result<(Report, Duration), Box<dyn Error>> {
+    let input = io.input.join(file);
+    let (tracks, input_format) = read_input(&input, io.input_format)?;
+
+    let now = Instant::now();
+    let (compressed, _) =
+        compress_tracks(io, compressor, &tracks, input_format).map_err(|e| format!("{}: {}", input.display(), e))?;
+    let elapsed = now.elapsed();
+
+    let output_format = match io.output_format {
+        OutputFormat::Auto => match input_format {
+            InputFormat::Gpx => OutputFormat::Gpx,
+            InputFormat::Fleet => OutputFormat::Fleet,
+            _ => OutputFormat::Csv,
+        },
+        format => format,
+    };
+    let mut output = OsString::from(io.output.join(output));
+    output.push(match output_format {
+        OutputFormat::Gpx => ".gpx",
+        OutputFormat::Geojson => ".geojson",
+        OutputFormat::Csv | OutputFormat::Fleet | OutputFormat::Auto => ".csv",
+    });
+    let output = PathBuf::from(output);
+    if output == summary {
+        let message = "output would overwrite the summary, pick another with --summary";
+        return Err(format!("{}: {}", input.display(), message).into());
+    }
+    if let Some(dir) = output.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    write_output(&output, output_format, &compressed)?;
+
+    Ok((evaluate_tracks(&tracks, &compressed, io.distance), elapsed))
+}
+
+fn write_summary(path: &Path, rows: &[FileSummary]) -> Result<(), Box<dyn Error>> {
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(["file", "points", "kept", "ratio", "max_sed", "mean_sed", "time_ms", "error"])?;
+    for row in rows {
+        let file = row.file.to_string_lossy().into_owned();
+        match &row.result {
+            Ok((report, time)) => writer.write_record([
+                file,
+                report.original_points.to_string(),
+                report.compressed_points.to_string(),
+                report.compression_ratio.to_string(),
+                report.sed.max.to_string(),
+                report.sed.mean.to_string(),
+                (time.as_secs_f64() * 1e3).to_string(),
+                String::new(),
+            ])?,
+            Err(e) => {
+                let mut record = vec![file];
+                record.resize(7, String::new());
+                record.push(e.clone());
+                writer.write_record(record)?
+            }
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
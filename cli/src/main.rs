@@ -0,0 +1,646 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use trajcomp::distance::DistanceModel;
+use trajcomp::metric::Metric;
+use trajcomp::evaluation::{evaluate_with_model, Report};
+use trajcomp::io::plt::PLT_SIGNATURE;
+use trajcomp::io::{
+    compress_fleet_with, is_fleet_header, parse_csv, parse_fleet_csv, parse_gpx, parse_plt, parse_txt, write_csv_to,
+    write_fleet_csv_to, write_geojson_segments_to, write_geojson_to, write_gpx_to, GpxTrack, ObjectTrack,
+};
+use trajcomp::tuning::search_epsilon;
+use trajcomp::{Algorithm, Point};
+
+use crate::sweep::SweepArgs;
+
+mod batch;
+mod sweep;
+
+/// Compress GPS trajectories.
+///
+/// Reads a trajectory, compresses it with the chosen algorithm and writes the
+/// kept points. A report of the error introduced is printed on stderr.
+#[derive(Parser)]
+#[command(name = "trajcomp", version)]
+struct Cli {
+    #[command(flatten)]
+    io: IoArgs,
+
+    #[command(subcommand)]
+    algorithm: AlgorithmCommand,
+}
+
+#[derive(Args)]
+struct IoArgs {
+    /// Input trajectory, `-` for stdin, or a directory to compress every
+    /// file with one of the --extensions under it
+    #[arg(short, long, global = true, default_value = "-")]
+    input: PathBuf,
+
+    /// Output file, `-` for stdout; the output directory when the input is a
+    /// directory
+    #[arg(short, long, global = true, default_value = "-")]
+    output: PathBuf,
+
+    /// Input format; `auto` goes by extension, then by content
+    #[arg(long, global = true, value_enum, default_value_t = InputFormat::Auto)]
+    input_format: InputFormat,
+
+    /// Output format; `auto` goes by extension and defaults to CSV
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Auto)]
+    output_format: OutputFormat,
+
+    /// Distance model: planar (degrees), equirectangular, haversine or
+    /// ellipsoidal (metres)
+    #[arg(long, global = true, default_value_t = DistanceModel::Planar)]
+    distance: DistanceModel,
+
+    /// Also write the error report as JSON to this file, `-` for stdout when
+    /// the output goes to a file
+    #[arg(long, global = true)]
+    report: Option<PathBuf>,
+
+    /// Do not print the timing and error report on stderr
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    /// Compress the objects of a fleet file on all cores
+    #[arg(long, global = true)]
+    parallel: bool,
+
+    /// Files compressed at once when the input is a directory, 0 for one per
+    /// core
+    #[arg(short, long, global = true, default_value_t = 0)]
+    jobs: usize,
+
+    /// Per-file summary CSV when the input is a directory; defaults to
+    /// summary.csv in the output directory
+    #[arg(long, global = true)]
+    summary: Option<PathBuf>,
+
+    /// Comma separated extensions of the files compressed when the input is
+    /// a directory. GeoLife's labels.txt files are always skipped
+    #[arg(long, global = true, value_delimiter = ',', default_value = "plt,txt,csv,gpx")]
+    extensions: Vec<String>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum InputFormat {
+    Auto,
+    /// Whitespace separated `lat lon unix_time` lines
+    Txt,
+    /// Raw GeoLife trajectory
+    Plt,
+    /// GPX 1.1 tracks
+    Gpx,
+    /// Headerless `lat,lon,time` records
+    Csv,
+    /// `id,lat,lon,time` records of many objects; detected by a header
+    /// naming the id column, if any
+    Fleet,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Auto,
+    /// Headerless `lat,lon,time` records
+    Csv,
+    /// GPX 1.1, keeping track and segment boundaries
+    Gpx,
+    /// GeoJSON LineString feature, or MultiLineString for several segments
+    Geojson,
+    /// Headerless `id,lat,lon,time` records; the default for fleet input
+    Fleet,
+}
+
+#[derive(Subcommand)]
+enum AlgorithmCommand {
+    /// Keep every STEP-th point and the last one
+    Uniform {
+        /// Number of points between two kept points
+        #[arg(long)]
+        step: usize,
+    },
+    /// Opening window bounded by the perpendicular distance (OPW)
+    Opw {
+        /// Largest perpendicular distance of a dropped point from its segment
+        #[arg(long, required_unless_present = "target_ratio")]
+        epsilon: Option<f64>,
+        #[command(flatten)]
+        target: TargetRatio,
+    },
+    /// Opening window bounded by the synchronized Euclidean distance (OPW-TR)
+    OpwTr {
+        /// Largest synchronized Euclidean distance of a dropped point
+        #[arg(long, required_unless_present = "target_ratio")]
+        epsilon: Option<f64>,
+        #[command(flatten)]
+        target: TargetRatio,
+    },
+    /// Keep a point once the accumulated deviation from the last kept heading
+    /// exceeds EPSILON
+    DeadReckoning {
+        /// Largest accumulated deviation from the heading
+        #[arg(long, required_unless_present = "target_ratio")]
+        epsilon: Option<f64>,
+        #[command(flatten)]
+        target: TargetRatio,
+    },
+    /// Keep a point once it lies more than EPSILON from the position predicted
+    /// by the speed and heading at the last kept point
+    VelocityDeadReckoning {
+        /// Largest distance of a dropped point from its predicted position, in
+        /// metres; on the great circle unless --distance picks a metre model
+        #[arg(long, required_unless_present = "target_ratio")]
+        epsilon: Option<f64>,
+        #[command(flatten)]
+        target: TargetRatio,
+    },
+    /// SQUISH: bounded buffer evicting the point with the lowest accumulated SED
+    Squish {
+        /// Fraction of the points to keep, in (0, 1]
+        #[arg(long)]
+        ratio: f64,
+    },
+    /// SQUISH-E(λ, μ): compress by at least LAMBDA, then while SED stays under MU
+    SquishE {
+        /// Lower bound of the compression ratio (original / kept), at least 1
+        #[arg(long)]
+        lambda: f64,
+        /// SED error bound for the second pass, 0 to disable it
+        #[arg(long)]
+        mu: f64,
+    },
+    /// STTrace: like SQUISH, recomputing the SED of an evicted point's neighbours
+    Sttrace {
+        /// Fraction of the points to keep, in (0, 1]
+        #[arg(long)]
+        ratio: f64,
+    },
+    /// Keep a point when speed or heading deviate from both the sampled and
+    /// the original trajectory
+    Threshold {
+        /// Speed deviation threshold, in distance units per second
+        #[arg(long)]
+        speed: f64,
+        /// Orientation deviation threshold, in radians
+        #[arg(long)]
+        orientation: f64,
+    },
+    /// Offline Douglas-Peucker bounded by the perpendicular distance
+    DouglasPeucker {
+        /// Largest perpendicular distance of a dropped point from its segment
+        #[arg(long, required_unless_present = "target_ratio")]
+        epsilon: Option<f64>,
+        #[command(flatten)]
+        target: TargetRatio,
+    },
+    /// Offline Top-Down Time-Ratio bounded by the synchronized Euclidean
+    /// distance (TD-TR)
+    TdTr {
+        /// Largest synchronized Euclidean distance of a dropped point
+        #[arg(long, required_unless_present = "target_ratio")]
+        epsilon: Option<f64>,
+        #[command(flatten)]
+        target: TargetRatio,
+    },
+    /// Offline Visvalingam-Whyatt: drop the point with the smallest effective
+    /// area until RATIO of the points are left
+    Visvalingam {
+        /// Fraction of the points to keep, in (0, 1]
+        #[arg(long)]
+        ratio: f64,
+    },
+    /// Offline Visvalingam-Whyatt: drop every point with an effective area
+    /// under AREA
+    VisvalingamArea {
+        /// Smallest effective area of a kept point, in square distance units
+        #[arg(id = "epsilon", long = "area", value_name = "AREA", required_unless_present = "target_ratio")]
+        area: Option<f64>,
+        #[command(flatten)]
+        target: TargetRatio,
+    },
+    /// Visvalingam-Whyatt over a SQUISH-like buffer evicting the point with
+    /// the smallest effective area
+    BufferedVisvalingam {
+        /// Fraction of the points to keep, in (0, 1]
+        #[arg(long)]
+        ratio: f64,
+    },
+    /// Sliding window keeping a point once a point in the window has an error
+    /// above EPSILON against the segment from the window start
+    SlidingWindow {
+        /// Error metric: ped, sed, dad (direction, in radians) or speed
+        #[arg(long, default_value_t = Metric::Ped)]
+        metric: Metric,
+        /// Largest error of a dropped point, in the unit of the metric
+        #[arg(long, required_unless_present = "target_ratio")]
+        epsilon: Option<f64>,
+        #[command(flatten)]
+        target: TargetRatio,
+    },
+    /// Offline bottom-up merging of adjacent segments while the error of the
+    /// merged segment stays within EPSILON
+    BottomUp {
+        /// Error metric: ped, sed, dad (direction, in radians) or speed
+        #[arg(long, default_value_t = Metric::Ped)]
+        metric: Metric,
+        /// Largest error of a dropped point, in the unit of the metric
+        #[arg(long, required_unless_present = "target_ratio")]
+        epsilon: Option<f64>,
+        #[command(flatten)]
+        target: TargetRatio,
+    },
+    /// Run every algorithm over a grid of its parameters and write a CSV row
+    /// of points kept, ratio, SED and runtime per run
+    Sweep(SweepArgs),
+}
+
+#[derive(Args)]
+struct TargetRatio {
+    /// Instead of EPSILON (AREA for visvalingam-area), search for the
+    /// smallest value that keeps at most this fraction of the points, in (0, 1]
+    #[arg(long, conflicts_with = "epsilon")]
+    target_ratio: Option<f64>,
+}
+
+impl AlgorithmCommand {
+    // The epsilon of a searched algorithm is a placeholder
+    fn algorithm(&self) -> Algorithm {
+        match *self {
+            AlgorithmCommand::Uniform { step } => Algorithm::Uniform { step },
+            AlgorithmCommand::Opw { epsilon, .. } => Algorithm::Opw { epsilon: epsilon.unwrap_or(0.0) },
+            AlgorithmCommand::OpwTr { epsilon, .. } => Algorithm::OpwTr { epsilon: epsilon.unwrap_or(0.0) },
+            AlgorithmCommand::DeadReckoning { epsilon, .. } => Algorithm::DeadReckoning { epsilon: epsilon.unwrap_or(0.0) },
+            AlgorithmCommand::VelocityDeadReckoning { epsilon, .. } => Algorithm::VelocityDeadReckoning { epsilon: epsilon.unwrap_or(0.0) },
+            AlgorithmCommand::Squish { ratio } => Algorithm::Squish { ratio },
+            AlgorithmCommand::SquishE { lambda, mu } => Algorithm::SquishE { lambda, mu },
+            AlgorithmCommand::Sttrace { ratio } => Algorithm::STTrace { ratio },
+            AlgorithmCommand::Threshold { speed, orientation } => Algorithm::Threshold { speed, orientation },
+            AlgorithmCommand::DouglasPeucker { epsilon, .. } => Algorithm::DouglasPeucker { epsilon: epsilon.unwrap_or(0.0) },
+            AlgorithmCommand::TdTr { epsilon, .. } => Algorithm::TdTr { epsilon: epsilon.unwrap_or(0.0) },
+            AlgorithmCommand::Visvalingam { ratio } => Algorithm::Visvalingam { ratio },
+            AlgorithmCommand::VisvalingamArea { area, .. } => Algorithm::VisvalingamArea { area: area.unwrap_or(0.0) },
+            AlgorithmCommand::BufferedVisvalingam { ratio } => Algorithm::BufferedVisvalingam { ratio },
+            AlgorithmCommand::SlidingWindow { metric, epsilon, .. } => Algorithm::SlidingWindow { metric, epsilon: epsilon.unwrap_or(0.0) },
+            AlgorithmCommand::BottomUp { metric, epsilon, .. } => Algorithm::BottomUp { metric, epsilon: epsilon.unwrap_or(0.0) },
+            AlgorithmCommand::Sweep(_) => unreachable!("a sweep runs every algorithm"),
+        }
+    }
+
+    fn target_ratio(&self) -> Option<f64> {
+        match self {
+            AlgorithmCommand::Opw { target, .. }
+            | AlgorithmCommand::OpwTr { target, .. }
+            | AlgorithmCommand::DeadReckoning { target, .. }
+            | AlgorithmCommand::VelocityDeadReckoning { target, .. }
+            | AlgorithmCommand::DouglasPeucker { target, .. }
+            | AlgorithmCommand::TdTr { target, .. }
+            | AlgorithmCommand::VisvalingamArea { target, .. }
+            | AlgorithmCommand::SlidingWindow { target, .. }
+            | AlgorithmCommand::BottomUp { target, .. } => target.target_ratio,
+            _ => None,
+        }
+    }
+}
+
+// An epsilon searched for and whether it reached the target ratio
+type Searched = (f64, bool);
+
+// The epsilon searched for a fleet object or a track segment
+struct Search {
+    id: String,
+    epsilon: f64,
+    reached: bool,
+}
+
+/// An algorithm, with its epsilon searched for every trajectory when a target
+/// ratio is given.
+#[derive(Copy, Clone)]
+struct Compressor {
+    algorithm: Algorithm,
+    target_ratio: Option<f64>,
+}
+
+impl Compressor {
+    // The kept points and, when searched, the epsilon chosen and whether it
+    // reached the target ratio
+    fn compress(&self, points: &[Point], model: DistanceModel) -> trajcomp::Result<(Vec<Point>, Option<Searched>)> {
+        match self.target_ratio {
+            Some(target_ratio) => {
+                let search = search_epsilon(points, target_ratio, |points, epsilon| {
+                    self.algorithm.with_epsilon(epsilon).unwrap_or(self.algorithm).compress(points, model)
+                })?;
+                Ok((search.compressed, Some((search.epsilon, search.reached))))
+            }
+            None => Ok((self.algorithm.compress(points, model)?, None)),
+        }
+    }
+}
+
+impl fmt::Display for Compressor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.target_ratio {
+            Some(target_ratio) => {
+                write!(f, "{}(", self.algorithm.name())?;
+                if let Some(metric) = self.algorithm.metric() {
+                    write!(f, "metric={}, ", metric)?;
+                }
+                write!(f, "target_ratio={})", target_ratio)
+            }
+            None => write!(f, "{}", self.algorithm),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let AlgorithmCommand::Sweep(args) = &cli.algorithm {
+        return exit_code(sweep::run(&cli.io, args));
+    }
+    let compressor = Compressor { algorithm: cli.algorithm.algorithm(), target_ratio: cli.algorithm.target_ratio() };
+    let valid = match compressor.target_ratio {
+        Some(ratio) if !(ratio > 0.0 && ratio <= 1.0) => Err(format!("target ratio must be in (0, 1], got {}", ratio)),
+        _ => compressor.algorithm.validate(),
+    };
+    if let Err(e) = valid {
+        Cli::command().error(ErrorKind::ValueValidation, e).exit();
+    }
+    // Both would be interleaved on stdout
+    if cli.io.report.as_deref().is_some_and(is_std) && is_std(&cli.io.output) {
+        Cli::command()
+            .error(ErrorKind::ArgumentConflict, "--report - needs the compressed output in a file (--output)")
+            .exit();
+    }
+
+    exit_code(run(&cli.io, compressor))
+}
+
+fn exit_code(result: Result<(), Box<dyn Error>>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("trajcomp: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(io: &IoArgs, compressor: Compressor) -> Result<(), Box<dyn Error>> {
+    if io.input.is_dir() {
+        return batch::run(io, compressor);
+    }
+
+    // Read datapoints
+    let (tracks, input_format) = read_input(&io.input, io.input_format)?;
+
+    let now = Instant::now();
+    let (compressed, searches) = compress_tracks(io, compressor, &tracks, input_format)
+        .map_err(|e| format!("{}: {}", io.input.display(), e))?;
+    let elapsed = now.elapsed();
+    let epsilons: Vec<f64> = searches.iter().filter(|search| search.reached).map(|search| search.epsilon).collect();
+
+    let output_format = match io.output_format {
+        OutputFormat::Auto if input_format == InputFormat::Fleet => match extension(&io.output).as_str() {
+            "gpx" | "geojson" => OutputFormat::Auto,
+            _ => OutputFormat::Fleet,
+        },
+        format => format,
+    };
+    write_output(&io.output, output_format, &compressed)?;
+
+    // Report error introduced
+    let report = evaluate_tracks(&tracks, &compressed, io.distance);
+    if !io.quiet {
+        eprintln!("{} compression time: {:?}", compressor, elapsed);
+        match epsilons.as_slice() {
+            [] => {}
+            [epsilon] => eprintln!("epsilon: {}", epsilon),
+            epsilons => {
+                let min = epsilons.iter().cloned().fold(f64::INFINITY, f64::min);
+                let max = epsilons.iter().cloned().fold(0.0, f64::max);
+                eprintln!("epsilon: {} to {} over {} trajectories", min, max, epsilons.len());
+            }
+        }
+        eprintln!("{}", report);
+    }
+    for search in searches.iter().filter(|search| !search.reached) {
+        let target_ratio = compressor.target_ratio.unwrap_or_default();
+        eprintln!("trajcomp: warning: target ratio {} not reached for {}", target_ratio, search.id);
+    }
+    if let Some(path) = &io.report {
+        let mut json = serde_json::to_value(&report)?;
+        if compressor.target_ratio.is_some() {
+            // null where the target was not reached
+            let epsilons: Vec<serde_json::Value> = searches
+                .iter()
+                .map(|search| serde_json::json!({ "id": search.id, "epsilon": search.reached.then_some(search.epsilon) }))
+                .collect();
+            json["epsilons"] = epsilons.into();
+        }
+        let json = serde_json::to_string_pretty(&json)?;
+        if is_std(path) {
+            writeln!(io::stdout(), "{}", json)?;
+        } else {
+            fs::write(path, json + "\n").map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+    }
+    Ok(())
+}
+
+// The compressed tracks and the epsilons searched for them, if any, in
+// input order
+fn compress_tracks(
+    io: &IoArgs,
+    compressor: Compressor,
+    tracks: &[GpxTrack],
+    input_format: InputFormat,
+) -> trajcomp::Result<(Vec<GpxTrack>, Vec<Search>)> {
+    let mut searches = Vec::new();
+    let compressed = if input_format == InputFormat::Fleet {
+        // Compress every object on its own
+        let objects: Vec<ObjectTrack> = tracks.iter().enumerate().map(|(i, track)| to_object(i, track)).collect();
+        let compress = |points: &[Point]| compressor.compress(points, io.distance);
+        compress_fleet_with(&objects, compress, io.parallel)?
+            .into_iter()
+            .map(|(object, searched)| {
+                searches.extend(searched.map(|(epsilon, reached)| Search { id: object.id.clone(), epsilon, reached }));
+                to_track(object)
+            })
+            .collect()
+    } else {
+        // Compress every segment on its own, leaving empty ones empty
+        let mut compressed = Vec::new();
+        for (i, track) in tracks.iter().enumerate() {
+            let name = track.name.clone().unwrap_or_else(|| (i + 1).to_string());
+            let mut segments = Vec::new();
+            for (j, segment) in track.segments.iter().enumerate() {
+                if segment.is_empty() {
+                    segments.push(Vec::new());
+                    continue;
+                }
+                let (kept, searched) = compressor.compress(segment, io.distance)?;
+                // Segments are numbered from 1 when there are several
+                let id = if track.segments.len() > 1 { format!("{}/{}", name, j + 1) } else { name.clone() };
+                searches.extend(searched.map(|(epsilon, reached)| Search { id, epsilon, reached }));
+                segments.push(kept);
+            }
+            compressed.push(GpxTrack { name: track.name.clone(), segments });
+        }
+        compressed
+    };
+    Ok((compressed, searches))
+}
+
+// Error report over all segments, each measured against its own compression
+fn evaluate_tracks(tracks: &[GpxTrack], compressed: &[GpxTrack], model: DistanceModel) -> Report {
+    let reports: Vec<Report> = tracks
+        .iter()
+        .zip(compressed)
+        .flat_map(|(track, kept)| track.segments.iter().zip(&kept.segments))
+        .map(|(segment, kept)| evaluate_with_model(segment, kept, model))
+        .collect();
+    Report::combine(&reports)
+}
+
+fn is_std(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+fn extension(path: &Path) -> String {
+    path.extension().and_then(|ext| ext.to_str()).unwrap_or("").to_ascii_lowercase()
+}
+
+fn flatten(tracks: &[GpxTrack]) -> Vec<Point> {
+    tracks.iter().flat_map(|track| track.segments.iter().flatten().copied()).collect()
+}
+
+// Tracks without a name are numbered from 1
+fn to_object(index: usize, track: &GpxTrack) -> ObjectTrack {
+    ObjectTrack {
+        id: track.name.clone().unwrap_or_else(|| (index + 1).to_string()),
+        points: track.segments.iter().flatten().copied().collect(),
+    }
+}
+
+fn to_track(object: ObjectTrack) -> GpxTrack {
+    GpxTrack { name: Some(object.id), segments: vec![object.points] }
+}
+
+// The tracks read, with the format they were read in
+fn read_input(path: &Path, format: InputFormat) -> Result<(Vec<GpxTrack>, InputFormat), Box<dyn Error>> {
+    let mut data = Vec::new();
+    if is_std(path) {
+        io::stdin().read_to_end(&mut data)?;
+    } else {
+        data = fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    let format = match format {
+        InputFormat::Auto => detect_format(path, &data),
+        format => format,
+    };
+    let single = |points: Vec<Point>| vec![GpxTrack { name: None, segments: vec![points] }];
+    let tracks = match format {
+        InputFormat::Gpx => parse_gpx(&data[..]),
+        InputFormat::Plt => parse_plt(&data[..]).map(single),
+        InputFormat::Csv => parse_csv(&data[..]).map(single),
+        InputFormat::Fleet => parse_fleet_csv(&data[..]).map(|objects| objects.into_iter().map(to_track).collect()),
+        InputFormat::Txt | InputFormat::Auto => parse_txt(&data[..]).map(single),
+    };
+    let tracks = tracks.map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok((tracks, format))
+}
+
+fn detect_format(path: &Path, data: &[u8]) -> InputFormat {
+    let head = String::from_utf8_lossy(&data[..data.len().min(1024)]);
+    let first_line = head.lines().next().unwrap_or("");
+    // Only a header naming the id column tells a fleet file apart; a
+    // headerless one needs --input-format fleet
+    let csv_format = || if is_fleet_header(first_line) { InputFormat::Fleet } else { InputFormat::Csv };
+    match extension(path).as_str() {
+        "gpx" => return InputFormat::Gpx,
+        "csv" => return csv_format(),
+        _ => {}
+    }
+    if first_line.starts_with(PLT_SIGNATURE) {
+        InputFormat::Plt
+    } else if head.trim_start().starts_with('<') {
+        InputFormat::Gpx
+    } else if first_line.contains(',') {
+        csv_format()
+    } else {
+        InputFormat::Txt
+    }
+}
+
+fn write_output(path: &Path, format: OutputFormat, tracks: &[GpxTrack]) -> Result<(), Box<dyn Error>> {
+    let format = match format {
+        OutputFormat::Auto => match extension(path).as_str() {
+            "gpx" => OutputFormat::Gpx,
+            "geojson" => OutputFormat::Geojson,
+            _ => OutputFormat::Csv,
+        },
+        format => format,
+    };
+    if format == OutputFormat::Geojson && tracks.len() > 1 {
+        return Err(format!("{}: GeoJSON output holds a single trajectory, not {}", path.display(), tracks.len()).into());
+    }
+    // Joining segments would add an edge between them and take time backwards
+    let segments = tracks.iter().map(|track| track.segments.iter().filter(|s| !s.is_empty()).count());
+    if format == OutputFormat::Csv && segments.clone().sum::<usize>() > 1 {
+        let count = segments.sum::<usize>();
+        return Err(format!("{}: CSV output holds a single segment, not {}; write GPX instead", path.display(), count).into());
+    }
+    if format == OutputFormat::Fleet && segments.clone().any(|count| count > 1) {
+        return Err(format!("{}: fleet output holds a single segment per object; write GPX instead", path.display()).into());
+    }
+    let mut writer: Box<dyn Write> = if is_std(path) {
+        Box::new(io::stdout().lock())
+    } else {
+        let file = File::create(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Box::new(BufWriter::new(file))
+    };
+    match format {
+        OutputFormat::Gpx => write_gpx_to(tracks, &mut writer)?,
+        OutputFormat::Geojson => match tracks {
+            [track] => {
+                let segments: Vec<Vec<Point>> = track.segments.iter().filter(|s| !s.is_empty()).cloned().collect();
+                write_geojson_segments_to(&segments, &mut writer)?
+            }
+            _ => write_geojson_to(&[], &mut writer)?,
+        },
+        OutputFormat::Fleet => {
+            let objects: Vec<ObjectTrack> = tracks.iter().enumerate().map(|(i, track)| to_object(i, track)).collect();
+            write_fleet_csv_to(&objects, &mut writer)?
+        }
+        OutputFormat::Csv | OutputFormat::Auto => write_csv_to(&flatten(tracks), &mut writer)?,
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fleet_csv_needs_an_id_header() {
+        let path = Path::new("fleet.csv");
+        assert!(detect_format(path, b"id,lat,lon,time\na,40,116,0\n") == InputFormat::Fleet);
+        assert!(detect_format(path, b"lat,lon,time,vehicle_id\n40,116,0,a\n") == InputFormat::Fleet);
+        assert!(detect_format(Path::new("-"), b"object_id,lat,lon,time\na,40,116,0\n") == InputFormat::Fleet);
+        // Without a header, four columns may just as well be lat,lon,time,alt
+        assert!(detect_format(path, b"a,40,116,0\nb,41,117,0\n") == InputFormat::Csv);
+        assert!(detect_format(path, b"40,116,0,150\n") == InputFormat::Csv);
+        assert!(detect_format(path, b"lat,lon,time,alt\n40,116,0,150\n") == InputFormat::Csv);
+    }
+}
@@ -0,0 +1,141 @@
+//! Sweep mode: every algorithm over a grid of its parameters, written as one
+//! CSV row per run for plotting the compression ratio against the error.
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Write};
+use std::time::Instant;
+
+use clap::Args;
+use trajcomp::distance::DistanceModel;
+use trajcomp::evaluation::{evaluate_metric, ErrorStats};
+use trajcomp::io::GpxTrack;
+use trajcomp::metric::Metric;
+use trajcomp::Algorithm;
+
+use crate::{compress_tracks, is_std, read_input, Compressor, IoArgs};
+
+#[derive(Args)]
+pub(crate) struct SweepArgs {
+    /// Comma separated algorithms to sweep, all of them by default
+    #[arg(long, value_delimiter = ',')]
+    algorithms: Vec<String>,
+
+    /// Error metric of the max and mean columns: ped, sed, dad or speed
+    #[arg(long, default_value_t = Metric::Sed)]
+    metric: Metric,
+}
+
+const EPSILON_STEPS: [f64; 13] = [0.1, 0.2, 0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0];
+const RATIOS: [f64; 8] = [0.01, 0.02, 0.05, 0.1, 0.2, 0.3, 0.5, 0.7];
+const LAMBDAS: [f64; 7] = [2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0];
+const STEPS: [usize; 8] = [2, 3, 5, 10, 20, 50, 100, 200];
+const SPEED_STEPS: [f64; 5] = [1.0, 2.0, 5.0, 10.0, 20.0];
+const ORIENTATIONS: [f64; 4] = [0.1, 0.2, 0.5, 1.0];
+const ANGLES: [f64; 6] = [0.05, 0.1, 0.2, 0.5, 1.0, 2.0];
+
+// Every algorithm with every parameter value of the grid. Distances are
+// scaled to about a metre (1e-5 degrees) under the planar model, and areas
+// are the squares of the distances.
+fn grid(model: DistanceModel) -> Vec<Algorithm> {
+    let unit = if model == DistanceModel::Planar { 1e-5 } else { 1.0 };
+    let epsilons = EPSILON_STEPS.map(|step| step * unit);
+    let mut grid = Vec::new();
+    grid.extend(STEPS.map(|step| Algorithm::Uniform { step }));
+    grid.extend(epsilons.map(|epsilon| Algorithm::Opw { epsilon }));
+    grid.extend(epsilons.map(|epsilon| Algorithm::OpwTr { epsilon }));
+    grid.extend(epsilons.map(|epsilon| Algorithm::DeadReckoning { epsilon }));
+    // Always in metres
+    grid.extend(EPSILON_STEPS.map(|epsilon| Algorithm::VelocityDeadReckoning { epsilon }));
+    grid.extend(RATIOS.map(|ratio| Algorithm::Squish { ratio }));
+    // SQUISH-E(λ) and SQUISH-E(μ)
+    grid.extend(LAMBDAS.map(|lambda| Algorithm::SquishE { lambda, mu: 0.0 }));
+    grid.extend(epsilons.map(|mu| Algorithm::SquishE { lambda: 1.0, mu }));
+    grid.extend(RATIOS.map(|ratio| Algorithm::STTrace { ratio }));
+    for speed in SPEED_STEPS {
+        grid.extend(ORIENTATIONS.map(|orientation| Algorithm::Threshold { speed: speed * unit, orientation }));
+    }
+    grid.extend(epsilons.map(|epsilon| Algorithm::DouglasPeucker { epsilon }));
+    grid.extend(epsilons.map(|epsilon| Algorithm::TdTr { epsilon }));
+    grid.extend(RATIOS.map(|ratio| Algorithm::Visvalingam { ratio }));
+    grid.extend(epsilons.map(|epsilon| Algorithm::VisvalingamArea { area: epsilon * epsilon }));
+    grid.extend(RATIOS.map(|ratio| Algorithm::BufferedVisvalingam { ratio }));
+    for metric in Metric::ALL {
+        // DAD is in radians whatever the distance model
+        let epsilons = if metric == Metric::Dad { ANGLES.to_vec() } else { epsilons.to_vec() };
+        grid.extend(epsilons.iter().map(|&epsilon| Algorithm::SlidingWindow { metric, epsilon }));
+        grid.extend(epsilons.iter().map(|&epsilon| Algorithm::BottomUp { metric, epsilon }));
+    }
+    grid
+}
+
+pub(crate) fn run(io: &IoArgs, args: &SweepArgs) -> Result<(), Box<dyn Error>> {
+    let mut grid = grid(io.distance);
+    if !args.algorithms.is_empty() {
+        let names: Vec<String> = args.algorithms.iter().map(|name| name.trim().replace('-', "_")).collect();
+        let mut known: Vec<&str> = grid.iter().map(|algorithm| algorithm.name()).collect();
+        known.sort_unstable();
+        known.dedup();
+        for name in &names {
+            if !known.contains(&name.as_str()) {
+                return Err(format!("unknown algorithm {:?}, expected one of {}", name, known.join(", ")).into());
+            }
+        }
+        grid.retain(|algorithm| names.iter().any(|name| algorithm.name() == name));
+    }
+
+    let (tracks, input_format) = read_input(&io.input, io.input_format)?;
+    let mut writer: Box<dyn Write> = if is_std(&io.output) {
+        Box::new(io::stdout().lock())
+    } else {
+        Box::new(File::create(&io.output).map_err(|e| format!("{}: {}", io.output.display(), e))?)
+    };
+    let mut writer = csv::Writer::from_writer(&mut writer);
+    let max = format!("max_{}", args.metric);
+    let mean = format!("mean_{}", args.metric);
+    writer.write_record(["algorithm", "params", "points", "kept", "ratio", &max, &mean, "runtime_ms"])?;
+
+    let now = Instant::now();
+    for algorithm in &grid {
+        let compressor = Compressor { algorithm: *algorithm, target_ratio: None };
+        let start = Instant::now();
+        let (compressed, _) = compress_tracks(io, compressor, &tracks, input_format)
+            .map_err(|e| format!("{}: {}: {}", io.input.display(), algorithm, e))?;
+        let runtime = start.elapsed();
+
+        let (points, kept, errors) = measure(&tracks, &compressed, args.metric, io.distance);
+        let mut params: Vec<String> = algorithm.metric().map(|metric| format!("metric={}", metric)).into_iter().collect();
+        params.extend(algorithm.params().iter().map(|(name, value)| format!("{}={}", name, value)));
+        writer.write_record([
+            algorithm.name().to_string(),
+            params.join(";"),
+            points.to_string(),
+            kept.to_string(),
+            (kept as f64 / points as f64).to_string(),
+            errors.max.to_string(),
+            errors.mean.to_string(),
+            (runtime.as_secs_f64() * 1e3).to_string(),
+        ])?;
+    }
+    writer.flush()?;
+    if !io.quiet {
+        eprintln!("{} runs in {:?}", grid.len(), now.elapsed());
+    }
+    Ok(())
+}
+
+// Points, kept points and errors under `metric` over every track segment
+fn measure(tracks: &[GpxTrack], compressed: &[GpxTrack], metric: Metric, model: DistanceModel) -> (usize, usize, ErrorStats) {
+    let segments: Vec<(&Vec<_>, &Vec<_>)> = tracks
+        .iter()
+        .zip(compressed)
+        .flat_map(|(track, kept)| track.segments.iter().zip(&kept.segments))
+        .collect();
+    let stats: Vec<(ErrorStats, usize)> = segments
+        .iter()
+        .map(|(segment, kept)| (evaluate_metric(segment, kept, metric.with_model(model)), segment.len()))
+        .collect();
+    let points = segments.iter().map(|(segment, _)| segment.len()).sum();
+    let kept = segments.iter().map(|(_, kept)| kept.len()).sum();
+    (points, kept, ErrorStats::combine(&stats))
+}
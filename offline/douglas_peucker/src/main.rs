@@ -0,0 +1,50 @@
+use std::env;
+use std::process;
+use std::time::Instant;
+use trajcomp::evaluation::evaluate;
+use trajcomp::io::{gpsreader, write_to_file};
+use trajcomp::offline::douglas_peucker;
+
+fn main() {
+
+    // Read arguments
+    let args: Vec<String> = env::args().collect();
+    let filename = &args[1];
+    let epsilon: f64 = args[2].parse().unwrap();
+    let save_filename = &args[3];
+
+    // Paths
+    let rel_path = String::from("./data/");
+    let filename_path = format!("{}{}",&rel_path,&filename);
+
+    // Read datapoints file
+    let points = match gpsreader(&filename_path) {
+        Ok(points) => points,
+        Err(e) => {
+            eprintln!("{}: {}", filename_path, e);
+            process::exit(1);
+        }
+    };
+
+    let now = Instant::now();
+    // Compress
+    let points_compr = match douglas_peucker(&points, epsilon) {
+        Ok(points_compr) => points_compr,
+        Err(e) => {
+            eprintln!("{}: {}", filename_path, e);
+            process::exit(1);
+        }
+    };
+    let elapsed = now.elapsed();
+    println!("Douglas-Peucker compression time: {:?}", elapsed);
+
+    // Report error introduced
+    println!("{}", evaluate(&points, &points_compr));
+
+    // Write to file
+    let output_csv = format!("{}{}",&rel_path,&save_filename);
+    if let Err(e) = write_to_file(&points_compr, &output_csv) {
+        eprintln!("{}: {}", output_csv, e);
+        process::exit(1);
+    }
+}
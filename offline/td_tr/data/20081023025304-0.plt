@@ -0,0 +1,908 @@
+39.984702 116.318417 1224701584
+39.984683 116.31845 1224701590
+39.984686 116.318417 1224701595
+39.984688 116.318385 1224701600
+39.984655 116.318263 1224701605
+39.984611 116.318026 1224701610
+39.984608 116.317761 1224701615
+39.984563 116.317517 1224701620
+39.984539 116.317294 1224701625
+39.984606 116.317065 1224701630
+39.984568 116.316911 1224701635
+39.984586 116.316716 1224701640
+39.984561 116.316527 1224701645
+39.984536 116.316354 1224701650
+39.984523 116.316188 1224701655
+39.984516 116.315963 1224701660
+39.984523 116.315823 1224701665
+39.984574 116.315611 1224701670
+39.984568 116.315407 1224701675
+39.984538 116.315148 1224701680
+39.984501 116.314907 1224701685
+39.984532 116.314808 1224701690
+39.984504 116.314625 1224701695
+39.984485 116.314426 1224701700
+39.984427 116.31424 1224701705
+39.984485 116.314042 1224701710
+39.98448 116.313818 1224701715
+39.984501 116.313659 1224701720
+39.984618 116.314323 1224701725
+39.984649 116.314107 1224701730
+39.984621 116.313941 1224701735
+39.984655 116.313724 1224701740
+39.984681 116.313521 1224701745
+39.984708 116.313311 1224701750
+39.984708 116.313099 1224701755
+39.984696 116.312921 1224701760
+39.984677 116.312746 1224701765
+39.984682 116.312525 1224701770
+39.984649 116.312332 1224701775
+39.984641 116.312123 1224701780
+39.984647 116.311917 1224701785
+39.984654 116.31172 1224701790
+39.984631 116.311569 1224701795
+39.984647 116.31138 1224701800
+39.984653 116.311189 1224701805
+39.984628 116.311026 1224701810
+39.984652 116.310854 1224701815
+39.984656 116.31066 1224701820
+39.984616 116.310534 1224701825
+39.984676 116.31033 1224701830
+39.984698 116.310063 1224701835
+39.984696 116.309937 1224701840
+39.984663 116.309851 1224701845
+39.984598 116.309749 1224701850
+39.98454 116.309656 1224701855
+39.984525 116.309503 1224701860
+39.984498 116.309348 1224701865
+39.984481 116.309173 1224701870
+39.984489 116.308993 1224701875
+39.98447 116.308827 1224701880
+39.98449 116.308638 1224701885
+39.98449 116.308485 1224701890
+39.984494 116.308351 1224701895
+39.984499 116.308201 1224701900
+39.984484 116.308049 1224701905
+39.984491 116.307902 1224701910
+39.984499 116.307809 1224701915
+39.984472 116.307687 1224701920
+39.984493 116.307548 1224701925
+39.984488 116.307434 1224701930
+39.984427 116.307292 1224701935
+39.984428 116.307126 1224701940
+39.984432 116.306943 1224701945
+39.984425 116.306801 1224701950
+39.984438 116.306633 1224701955
+39.984462 116.306486 1224701960
+39.984466 116.3063 1224701965
+39.984456 116.306047 1224701970
+39.984454 116.305854 1224701975
+39.984412 116.305682 1224701980
+39.98441 116.305466 1224701985
+39.984406 116.305281 1224701990
+39.984393 116.305023 1224701995
+39.984356 116.304909 1224702000
+39.984352 116.304704 1224702005
+39.984338 116.304543 1224702010
+39.984338 116.304315 1224702015
+39.984349 116.304083 1224702020
+39.984368 116.303934 1224702025
+39.984437 116.303813 1224702030
+39.984429 116.303672 1224702035
+39.984342 116.303444 1224702040
+39.984365 116.303298 1224702045
+39.984368 116.303132 1224702050
+39.984385 116.30292 1224702055
+39.98438 116.302754 1224702060
+39.984346 116.302601 1224702065
+39.984303 116.302457 1224702070
+39.984344 116.302271 1224702075
+39.984341 116.302094 1224702080
+39.984359 116.301888 1224702085
+39.984379 116.301693 1224702090
+39.984345 116.3015 1224702095
+39.984345 116.301309 1224702100
+39.984338 116.301107 1224702105
+39.984344 116.300877 1224702110
+39.984278 116.300674 1224702115
+39.984276 116.300462 1224702120
+39.984278 116.300307 1224702125
+39.984252 116.300156 1224702130
+39.984288 116.299943 1224702135
+39.984283 116.29981 1224702140
+39.984335 116.299744 1224702145
+39.984312 116.299703 1224702150
+39.984177 116.299699 1224702155
+39.98414 116.299697 1224702160
+39.984204 116.299721 1224702165
+39.984258 116.299736 1224702170
+39.984031 116.299573 1224702175
+39.98379 116.299578 1224702180
+39.983726 116.299566 1224702185
+39.983692 116.299496 1224702190
+39.983685 116.299476 1224702195
+39.983639 116.299425 1224702200
+39.983629 116.299353 1224702205
+39.983641 116.299279 1224702210
+39.983557 116.299235 1224702215
+39.983509 116.299238 1224702220
+39.983413 116.299267 1224702225
+39.983353 116.299305 1224702230
+39.983337 116.299321 1224702235
+39.9833 116.299314 1224702240
+39.9833 116.299314 1224702243
+39.983304 116.299307 1224702245
+39.983279 116.299311 1224702250
+39.983302 116.299302 1224702255
+39.983276 116.299265 1224702260
+39.983322 116.299191 1224702265
+39.983403 116.299147 1224702270
+39.983548 116.299083 1224702275
+39.983675 116.299031 1224702280
+39.983663 116.298932 1224702285
+39.983533 116.298903 1224702290
+39.98359 116.298869 1224702295
+39.983723 116.298825 1224702300
+39.983902 116.298773 1224702305
+39.984045 116.298725 1224702310
+39.984019 116.298663 1224702315
+39.995777 116.286798 1224706087
+39.996832 116.285446 1224706092
+39.984397 116.299292 1224706122
+39.984426 116.299329 1224706127
+39.984499 116.299413 1224706132
+39.984424 116.299467 1224706137
+39.98441 116.299477 1224706142
+39.984421 116.299569 1224706147
+39.984518 116.29953 1224706152
+39.984488 116.299645 1224706157
+39.98454 116.299523 1224706162
+39.98446 116.299528 1224706167
+39.984463 116.299508 1224706172
+39.984501 116.299473 1224706177
+39.984532 116.299451 1224706182
+39.984532 116.299451 1224706185
+39.984527 116.299451 1224706187
+39.984517 116.299447 1224706192
+39.984509 116.299438 1224706197
+39.984497 116.299411 1224706202
+39.984499 116.299405 1224706207
+39.984498 116.299407 1224706212
+39.984498 116.299407 1224706215
+39.984493 116.299412 1224706217
+39.984498 116.299466 1224706222
+39.984546 116.29941 1224706227
+39.984638 116.299318 1224706232
+39.98473 116.299315 1224706237
+39.984753 116.299305 1224706242
+39.984789 116.299297 1224706247
+39.984795 116.299391 1224706252
+39.984778 116.299504 1224706257
+39.984743 116.299617 1224706262
+39.984671 116.299625 1224706267
+39.984694 116.299536 1224706272
+39.984733 116.29952 1224706277
+39.983841 116.299902 1224706282
+39.983861 116.300177 1224706287
+39.983842 116.300329 1224706292
+39.983984 116.300308 1224706297
+39.984126 116.300188 1224706302
+39.984246 116.300058 1224706307
+39.984373 116.300019 1224706312
+39.984533 116.300043 1224706317
+39.984723 116.300058 1224706322
+39.984789 116.300151 1224706327
+39.984837 116.300216 1224706332
+39.984859 116.300332 1224706337
+39.984888 116.300514 1224706342
+39.984896 116.300689 1224706347
+39.984905 116.300885 1224706352
+39.984911 116.301049 1224706357
+39.984925 116.301221 1224706362
+39.98493 116.301391 1224706367
+39.984938 116.301568 1224706372
+39.984948 116.301741 1224706377
+39.984945 116.301915 1224706382
+39.984953 116.302055 1224706387
+39.984953 116.302264 1224706392
+39.98496 116.302488 1224706397
+39.984973 116.302679 1224706402
+39.984983 116.30286 1224706407
+39.985009 116.303037 1224706412
+39.985043 116.303258 1224706417
+39.98503 116.303496 1224706422
+39.985039 116.303685 1224706427
+39.985028 116.303884 1224706432
+39.984993 116.304067 1224706437
+39.985027 116.304163 1224706442
+39.985038 116.30426 1224706447
+39.985042 116.304459 1224706452
+39.985029 116.304647 1224706457
+39.985023 116.304811 1224706462
+39.985031 116.304927 1224706467
+39.985082 116.305192 1224706472
+39.985105 116.305348 1224706477
+39.985132 116.305499 1224706482
+39.985169 116.305649 1224706487
+39.985135 116.305834 1224706492
+39.985146 116.305984 1224706497
+39.985131 116.306178 1224706502
+39.985127 116.306363 1224706507
+39.985135 116.306531 1224706512
+39.985157 116.306711 1224706517
+39.98517 116.306893 1224706522
+39.985154 116.307092 1224706527
+39.985184 116.307341 1224706532
+39.98521 116.30751 1224706537
+39.985231 116.307633 1224706542
+39.98526 116.307735 1224706547
+39.98524 116.307933 1224706552
+39.985225 116.308133 1224706557
+39.985256 116.308313 1224706562
+39.985218 116.308579 1224706567
+39.985201 116.308774 1224706572
+39.985203 116.30898 1224706577
+39.985221 116.309152 1224706582
+39.985194 116.30931 1224706587
+39.985161 116.309494 1224706592
+39.98519 116.309701 1224706597
+39.985202 116.309888 1224706602
+39.985282 116.30998 1224706607
+39.985443 116.30998 1224706612
+39.985579 116.309969 1224706617
+39.985721 116.309961 1224706622
+39.98584 116.309941 1224706627
+39.98595 116.309978 1224706632
+39.986051 116.310034 1224706637
+39.986174 116.310025 1224706642
+39.986305 116.309956 1224706647
+39.986461 116.309885 1224706652
+39.986611 116.309862 1224706657
+39.986697 116.309893 1224706662
+39.986793 116.309956 1224706667
+39.986929 116.309974 1224706672
+39.987073 116.309987 1224706677
+39.987211 116.310004 1224706682
+39.987325 116.31007 1224706687
+39.987458 116.310084 1224706692
+39.987591 116.310088 1224706697
+39.987747 116.310049 1224706702
+39.987884 116.310027 1224706707
+39.988008 116.310059 1224706712
+39.988111 116.310052 1224706717
+39.988293 116.309902 1224706722
+39.988397 116.309931 1224706727
+39.988553 116.309866 1224706732
+39.988679 116.309824 1224706737
+39.988772 116.309784 1224706742
+39.988901 116.309793 1224706747
+39.989029 116.309833 1224706752
+39.989173 116.309866 1224706757
+39.989287 116.309905 1224706762
+39.989392 116.309949 1224706767
+39.989519 116.310031 1224706772
+39.989628 116.310049 1224706777
+39.989762 116.310024 1224706782
+39.989838 116.310098 1224706787
+39.989981 116.310115 1224706792
+39.990089 116.310169 1224706797
+39.990219 116.310215 1224706802
+39.990325 116.310258 1224706807
+39.990406 116.310277 1224706812
+39.99056 116.31019 1224706817
+39.99065 116.310134 1224706822
+39.990693 116.310114 1224706827
+39.990696 116.310111 1224706832
+39.990698 116.310105 1224706837
+39.990698 116.310103 1224706842
+39.990696 116.310101 1224706847
+39.990695 116.310095 1224706852
+39.990692 116.310092 1224706857
+39.99069 116.31009 1224706862
+39.990689 116.310088 1224706867
+39.990689 116.310087 1224706872
+39.990689 116.310087 1224706875
+39.990696 116.31009 1224706877
+39.990743 116.310066 1224706882
+39.990793 116.310109 1224706887
+39.990837 116.310205 1224706892
+39.990873 116.310318 1224706897
+39.990887 116.310479 1224706902
+39.990874 116.310657 1224706907
+39.990906 116.310827 1224706912
+39.990921 116.310971 1224706917
+39.990933 116.311149 1224706922
+39.990969 116.311312 1224706927
+39.990994 116.311507 1224706932
+39.991014 116.311693 1224706937
+39.99101 116.311915 1224706942
+39.991033 116.312107 1224706947
+39.991055 116.312282 1224706952
+39.991058 116.312473 1224706957
+39.991032 116.312666 1224706962
+39.991024 116.312853 1224706967
+39.991022 116.313047 1224706972
+39.991018 116.313244 1224706977
+39.991016 116.313469 1224706982
+39.991035 116.313681 1224706987
+39.991063 116.31384 1224706992
+39.991072 116.31403 1224706997
+39.991068 116.314209 1224707002
+39.991095 116.314385 1224707007
+39.991113 116.314596 1224707012
+39.991128 116.314797 1224707017
+39.991127 116.315003 1224707022
+39.991148 116.315193 1224707027
+39.991176 116.315342 1224707032
+39.991215 116.315529 1224707037
+39.991209 116.315643 1224707042
+39.991191 116.31579 1224707047
+39.991188 116.315958 1224707052
+39.991189 116.316172 1224707057
+39.991186 116.316381 1224707062
+39.991194 116.316575 1224707067
+39.991208 116.316756 1224707072
+39.99122 116.316999 1224707077
+39.991197 116.317235 1224707082
+39.991183 116.317452 1224707087
+39.991164 116.317661 1224707092
+39.991187 116.317874 1224707097
+39.991179 116.318083 1224707102
+39.991132 116.318231 1224707107
+39.991169 116.31848 1224707112
+39.991183 116.318691 1224707117
+39.991204 116.318904 1224707122
+39.991201 116.319098 1224707127
+39.991194 116.319315 1224707132
+39.991187 116.31949 1224707137
+39.991206 116.319683 1224707142
+39.991239 116.319866 1224707147
+39.991258 116.320062 1224707152
+39.991282 116.320255 1224707157
+39.991288 116.320445 1224707162
+39.991308 116.320622 1224707167
+39.991325 116.320803 1224707172
+39.991352 116.321002 1224707177
+39.991382 116.321179 1224707182
+39.991388 116.321384 1224707187
+39.991389 116.321583 1224707192
+39.991368 116.321736 1224707197
+39.99141 116.321874 1224707202
+39.991412 116.322004 1224707207
+39.991406 116.322176 1224707212
+39.991403 116.322296 1224707217
+39.991401 116.322436 1224707222
+39.991477 116.322668 1224707227
+39.991591 116.322784 1224707232
+39.991716 116.322778 1224707237
+39.991863 116.322774 1224707242
+39.992007 116.322784 1224707247
+39.992201 116.322812 1224707252
+39.992365 116.3228 1224707257
+39.992456 116.322797 1224707262
+39.9926 116.32281 1224707267
+39.992777 116.322891 1224707272
+39.992883 116.322891 1224707277
+39.993001 116.322713 1224707282
+39.993077 116.322617 1224707287
+39.993222 116.322651 1224707292
+39.993429 116.32276 1224707297
+39.993535 116.322744 1224707302
+39.99364 116.322658 1224707307
+39.993796 116.322579 1224707312
+39.994055 116.322804 1224707317
+39.994175 116.322829 1224707322
+39.994276 116.322786 1224707327
+39.994471 116.322763 1224707332
+39.994648 116.322723 1224707337
+39.99481 116.322641 1224707342
+39.994946 116.322625 1224707347
+39.995093 116.32262 1224707352
+39.995235 116.322606 1224707357
+39.995392 116.322607 1224707362
+39.995567 116.322533 1224707367
+39.995718 116.32252 1224707372
+39.995852 116.322517 1224707377
+39.995993 116.322556 1224707382
+39.99611 116.322544 1224707387
+39.996234 116.322552 1224707392
+39.996392 116.322481 1224707397
+39.996534 116.322464 1224707402
+39.996632 116.322425 1224707407
+39.996645 116.322412 1224707412
+39.996725 116.322348 1224707417
+39.996881 116.322337 1224707422
+39.997081 116.322428 1224707427
+39.997277 116.322428 1224707432
+39.997592 116.322314 1224707437
+39.99775 116.322301 1224707442
+39.997865 116.322317 1224707447
+39.997988 116.32231 1224707452
+39.998151 116.322261 1224707457
+39.998307 116.322268 1224707462
+39.998452 116.322236 1224707467
+39.998603 116.322225 1224707472
+39.998753 116.322225 1224707477
+39.998895 116.322254 1224707482
+39.999078 116.322196 1224707487
+39.999218 116.322176 1224707492
+39.999364 116.322161 1224707497
+39.999484 116.322148 1224707502
+39.999607 116.322178 1224707507
+39.999602 116.322324 1224707512
+39.999603 116.322434 1224707517
+39.999586 116.322587 1224707522
+39.999573 116.32276 1224707527
+39.999572 116.322932 1224707532
+39.999565 116.323134 1224707537
+39.999569 116.32328 1224707542
+39.999575 116.323448 1224707547
+39.999568 116.323605 1224707552
+39.999558 116.323605 1224707557
+39.999581 116.323732 1224707562
+39.999653 116.324069 1224707567
+39.999678 116.324258 1224707572
+39.999646 116.324405 1224707577
+39.999676 116.324452 1224707582
+39.999678 116.324453 1224707587
+39.999682 116.324455 1224707592
+39.999682 116.324455 1224707595
+39.999676 116.324456 1224707597
+39.999641 116.32451 1224707602
+39.999662 116.324481 1224707607
+39.999651 116.324477 1224707612
+39.999664 116.324493 1224707617
+39.999684 116.324511 1224707622
+39.999684 116.324511 1224707625
+39.999678 116.324511 1224707627
+39.999667 116.324512 1224707632
+39.999655 116.324513 1224707637
+39.999703 116.324496 1224707642
+39.99956 116.324498 1224707647
+39.999506 116.324545 1224707652
+39.999516 116.324531 1224707657
+39.999567 116.324478 1224707662
+39.999573 116.3246 1224707667
+39.999538 116.324639 1224707672
+39.999598 116.324684 1224707677
+39.999666 116.324722 1224707682
+39.999729 116.324801 1224707687
+39.999769 116.324886 1224707692
+40.004775 116.320158 1224726145
+40.004783 116.320388 1224726150
+40.004799 116.320545 1224726155
+40.004835 116.320683 1224726160
+40.004851 116.320835 1224726165
+40.004868 116.32101 1224726170
+40.004849 116.321153 1224726175
+40.004879 116.321277 1224726180
+40.004962 116.321318 1224726185
+40.005088 116.32128 1224726190
+40.00525 116.32126 1224726195
+40.005455 116.321389 1224726200
+40.005621 116.321455 1224726205
+40.005738 116.321491 1224726210
+40.005883 116.321518 1224726215
+40.006051 116.321521 1224726220
+40.006178 116.321463 1224726225
+40.00633 116.321461 1224726230
+40.006482 116.321474 1224726235
+40.006631 116.321459 1224726240
+40.006813 116.321447 1224726245
+40.006974 116.321454 1224726250
+40.007122 116.321396 1224726255
+40.007235 116.321356 1224726260
+40.00741 116.321395 1224726265
+40.007571 116.321412 1224726270
+40.007715 116.321414 1224726275
+40.007862 116.321426 1224726280
+40.008034 116.321437 1224726285
+40.00817 116.321441 1224726290
+40.008337 116.321425 1224726295
+40.008515 116.321421 1224726300
+40.008668 116.321446 1224726305
+40.008782 116.321443 1224726310
+40.008843 116.321385 1224726315
+40.008884 116.321402 1224726320
+40.00895 116.321449 1224726325
+40.009006 116.321502 1224726330
+40.009026 116.321564 1224726335
+40.009011 116.321623 1224726340
+40.009021 116.321625 1224726345
+40.009021 116.321625 1224726348
+40.00902 116.321631 1224726350
+40.009013 116.321659 1224726355
+40.008921 116.321484 1224726560
+40.008582 116.322217 1224726580
+40.008481 116.32244 1224726585
+40.008496 116.322406 1224726590
+40.00888 116.321628 1224726595
+40.00898 116.321587 1224726600
+40.00898 116.321544 1224726605
+40.008984 116.321525 1224726610
+40.008984 116.321525 1224726613
+40.008982 116.321517 1224726615
+40.008958 116.321457 1224726620
+40.008971 116.321436 1224726625
+40.009022 116.321434 1224726630
+40.009025 116.321374 1224726635
+40.00903 116.321397 1224726640
+40.009062 116.321462 1224726645
+40.009081 116.321486 1224726650
+40.00908 116.321479 1224726655
+40.00908 116.321479 1224726658
+40.009081 116.321481 1224726660
+40.009083 116.321491 1224726665
+40.009094 116.32152 1224726670
+40.009072 116.321585 1224726675
+40.009083 116.321467 1224726680
+40.00908 116.321442 1224726685
+40.009087 116.321453 1224726690
+40.009065 116.321479 1224726695
+40.009071 116.321517 1224726700
+40.009075 116.32156 1224726705
+40.009105 116.321616 1224726710
+40.00908 116.32156 1224726715
+40.009031 116.321491 1224726720
+40.008995 116.321407 1224726725
+40.008995 116.321407 1224726728
+40.008999 116.321412 1224726730
+40.008997 116.321399 1224726735
+40.008994 116.321389 1224726740
+40.008994 116.321389 1224726743
+40.008995 116.321392 1224726745
+40.009235 116.321472 1224726750
+40.009268 116.321446 1224726755
+40.00927 116.321447 1224726760
+40.00927 116.321447 1224726763
+40.009272 116.321451 1224726765
+40.00928 116.321473 1224726770
+40.009278 116.32147 1224726771
+40.009242 116.321445 1224726776
+40.009187 116.32143 1224726781
+40.009098 116.321437 1224726786
+40.009045 116.321485 1224726791
+40.009008 116.321449 1224726796
+40.008993 116.321501 1224726801
+40.009017 116.321558 1224726806
+40.008999 116.321511 1224726811
+40.008999 116.321511 1224726814
+40.008997 116.321518 1224726816
+40.008999 116.321537 1224726821
+40.009026 116.321518 1224726826
+40.009009 116.321499 1224726831
+40.009009 116.321499 1224726834
+40.009011 116.321506 1224726836
+40.009013 116.321522 1224726841
+40.009012 116.321576 1224726846
+40.009034 116.321569 1224726851
+40.009034 116.321569 1224726854
+40.009038 116.321575 1224726856
+40.009024 116.321614 1224726861
+40.009015 116.321526 1224726866
+40.008997 116.321432 1224726871
+40.008995 116.321455 1224726876
+40.008997 116.321485 1224726881
+40.009032 116.32151 1224726886
+40.009074 116.32148 1224726891
+40.009101 116.32153 1224726896
+40.009157 116.321596 1224726901
+40.009219 116.321657 1224726906
+40.009247 116.321721 1224726911
+40.009273 116.321781 1224726916
+40.009313 116.321871 1224726921
+40.009344 116.321952 1224726926
+40.009356 116.322007 1224726931
+40.009386 116.322103 1224726936
+40.009356 116.322121 1224726941
+40.009353 116.322135 1224726946
+40.009319 116.322152 1224726951
+40.009394 116.322162 1224726956
+40.009399 116.322179 1224726961
+40.009344 116.32219 1224726966
+40.009342 116.322177 1224726971
+40.009318 116.32219 1224726976
+40.009287 116.322206 1224726981
+40.00927 116.32222 1224726986
+40.009351 116.321916 1224727324
+40.009336 116.321838 1224727329
+40.009331 116.321811 1224727334
+40.009314 116.321823 1224727339
+40.009314 116.321833 1224727344
+40.009316 116.32185 1224727349
+40.009428 116.320888 1224727419
+40.008854 116.321493 1224727424
+40.008848 116.321349 1224727429
+40.008874 116.32116 1224727434
+40.008852 116.321109 1224727439
+40.008786 116.321037 1224727444
+40.008767 116.321012 1224727449
+40.008735 116.320977 1224727454
+40.008694 116.320964 1224727459
+40.008665 116.320937 1224727464
+40.008635 116.320886 1224727469
+40.008612 116.32083 1224727474
+40.008591 116.3208 1224727479
+40.008574 116.320772 1224727484
+40.008521 116.320688 1224727489
+40.008471 116.320669 1224727494
+40.008419 116.320654 1224727499
+40.008362 116.320619 1224727504
+40.008348 116.320573 1224727509
+40.008309 116.320547 1224727514
+40.008281 116.320543 1224727519
+40.008234 116.320499 1224727524
+40.008163 116.320473 1224727529
+40.008129 116.320418 1224727534
+40.008121 116.320359 1224727539
+40.008069 116.320335 1224727544
+40.008027 116.320309 1224727549
+40.00801 116.320287 1224727554
+40.007959 116.320241 1224727559
+40.007947 116.320238 1224727564
+40.007879 116.320237 1224727569
+40.007826 116.320239 1224727574
+40.007818 116.320239 1224727579
+40.007818 116.320239 1224727582
+40.007819 116.32024 1224727584
+40.007817 116.32023 1224727589
+40.007834 116.320181 1224727594
+40.007761 116.320093 1224727599
+40.007661 116.319986 1224727604
+40.007623 116.319953 1224727609
+40.007623 116.319953 1224727612
+40.007621 116.31995 1224727614
+40.007575 116.31987 1224727619
+40.007551 116.319732 1224727624
+40.007526 116.31965 1224727629
+40.007468 116.319517 1224727634
+40.007465 116.319506 1224727639
+40.007462 116.319493 1224727641
+40.007459 116.319436 1224727646
+40.007453 116.319403 1224727651
+40.007454 116.319391 1224727656
+40.007474 116.319376 1224727661
+40.0075 116.319364 1224727666
+40.007524 116.319357 1224727671
+40.007524 116.319321 1224727676
+40.007517 116.319312 1224727681
+40.007551 116.319307 1224727686
+40.007551 116.319307 1224727689
+40.007555 116.319313 1224727691
+40.00759 116.31934 1224727696
+40.00761 116.319355 1224727701
+40.007615 116.319369 1224727706
+40.007621 116.319385 1224727711
+40.007644 116.319403 1224727716
+40.007663 116.319417 1224727721
+40.007663 116.319417 1224727724
+40.007669 116.319414 1224727726
+40.007661 116.319384 1224727731
+40.007654 116.319321 1224727736
+40.007654 116.319266 1224727741
+40.00767 116.319215 1224727746
+40.007694 116.319181 1224727751
+40.007699 116.319151 1224727756
+40.007272 116.319756 1224727766
+40.007331 116.319657 1224727771
+40.007428 116.319432 1224727781
+40.007395 116.319354 1224727786
+40.007447 116.319379 1224727791
+40.007479 116.319371 1224727796
+40.007516 116.319316 1224727801
+40.007575 116.319238 1224727806
+40.007288 116.319088 1224727831
+40.007288 116.319088 1224727834
+40.007284 116.319084 1224727836
+40.007298 116.319036 1224727841
+40.007365 116.31903 1224727846
+40.007416 116.319007 1224727851
+40.007482 116.318957 1224727856
+40.007548 116.318852 1224727861
+40.007627 116.318775 1224727866
+40.007718 116.318705 1224727871
+40.007808 116.318623 1224727876
+40.007877 116.318546 1224727881
+40.007945 116.318487 1224727886
+40.00803 116.318444 1224727891
+40.008125 116.318365 1224727896
+40.008215 116.318248 1224727901
+40.008168 116.318293 1224727906
+40.008015 116.318471 1224727911
+40.008049 116.318444 1224727916
+40.008064 116.31838 1224727921
+40.008118 116.318326 1224727926
+40.008197 116.318275 1224727931
+40.008289 116.318251 1224727936
+40.008305 116.318267 1224727941
+40.008276 116.318339 1224727946
+40.008184 116.318474 1224727951
+40.008071 116.318568 1224727956
+40.007954 116.318639 1224727961
+40.007884 116.318684 1224727966
+40.007867 116.318699 1224727971
+40.007835 116.318706 1224727976
+40.007717 116.318842 1224727981
+40.007726 116.318826 1224727986
+40.007721 116.318792 1224727991
+40.007753 116.318741 1224727996
+40.007803 116.318672 1224728001
+40.007852 116.318565 1224728006
+40.007925 116.318494 1224728011
+40.008019 116.318455 1224728016
+40.006977 116.319607 1224728061
+40.007203 116.319334 1224728066
+40.00735 116.319131 1224728071
+40.007504 116.318956 1224728076
+40.007611 116.318793 1224728081
+40.007773 116.31865 1224728086
+40.007966 116.31854 1224728091
+40.007399 116.31912 1224728106
+40.007411 116.319124 1224728111
+40.00744 116.31909 1224728116
+40.007496 116.319085 1224728121
+40.00754 116.31909 1224728126
+40.007619 116.319094 1224728131
+40.007648 116.319041 1224728136
+40.007681 116.318962 1224728141
+40.007724 116.318892 1224728146
+40.007766 116.318825 1224728151
+40.007809 116.318799 1224728156
+40.007849 116.318754 1224728161
+40.007905 116.31869 1224728166
+40.007878 116.318784 1224728291
+40.007616 116.318863 1224728296
+40.007692 116.318817 1224728301
+40.007794 116.318827 1224728306
+40.007898 116.318829 1224728311
+40.007981 116.318788 1224728316
+40.008009 116.318763 1224728321
+40.008031 116.318756 1224728326
+40.008055 116.318753 1224728331
+40.008237 116.318816 1224728336
+40.008306 116.318941 1224728341
+40.008283 116.318897 1224728346
+40.0083 116.318936 1224728351
+40.007645 116.319454 1224728356
+40.007582 116.319409 1224728361
+40.007581 116.319385 1224728366
+40.007612 116.319403 1224728371
+40.007694 116.319429 1224728376
+40.007778 116.319475 1224728381
+40.007876 116.319505 1224728386
+40.007974 116.319529 1224728391
+40.008096 116.319537 1224728396
+40.008196 116.319549 1224728401
+40.008292 116.319591 1224728406
+40.008369 116.319618 1224728411
+40.008421 116.319597 1224728416
+40.008452 116.319548 1224728421
+40.008503 116.319525 1224728426
+40.008544 116.31949 1224728431
+40.008601 116.319482 1224728436
+40.008617 116.319483 1224728441
+40.008617 116.319483 1224728444
+40.008625 116.319489 1224728446
+40.00864 116.319488 1224728456
+40.008645 116.319484 1224728461
+40.008645 116.319484 1224728464
+40.008649 116.319481 1224728466
+40.008669 116.319455 1224728476
+40.006747 116.318549 1224728795
+40.006747 116.318549 1224728803
+40.006755 116.318544 1224728805
+40.006742 116.318571 1224728810
+40.006744 116.31856 1224728815
+40.006745 116.318572 1224728820
+40.006745 116.318581 1224728825
+40.006739 116.318568 1224728830
+40.006722 116.318548 1224728835
+40.006719 116.318565 1224728840
+40.006724 116.318625 1224728845
+40.006745 116.318817 1224728850
+40.006715 116.318884 1224728855
+40.006721 116.318965 1224728860
+40.006753 116.319072 1224728865
+40.006785 116.319175 1224728870
+40.00681 116.319239 1224728875
+40.006849 116.319286 1224728880
+40.006811 116.319309 1224728885
+40.006789 116.319351 1224728890
+40.006791 116.319398 1224728895
+40.006828 116.319424 1224728900
+40.006872 116.319419 1224728905
+40.006893 116.319381 1224728910
+40.006975 116.319294 1224728915
+40.007457 116.319674 1224728925
+40.007457 116.319674 1224728928
+40.007458 116.319676 1224728930
+40.007479 116.319695 1224728935
+40.007572 116.319786 1224728940
+40.007582 116.319816 1224728945
+40.007621 116.319834 1224728950
+40.007629 116.31989 1224728955
+40.007634 116.319966 1224728960
+40.007646 116.32005 1224728965
+40.00766 116.320119 1224728970
+40.007686 116.320243 1224728975
+40.007748 116.320301 1224728980
+40.007802 116.320389 1224728985
+40.007819 116.320364 1224728990
+40.007853 116.320343 1224728995
+40.007912 116.320328 1224729000
+40.007978 116.320325 1224729005
+40.007999 116.320339 1224729010
+40.008036 116.320394 1224729015
+40.008125 116.320486 1224729020
+40.008172 116.320571 1224729025
+40.008182 116.320646 1224729030
+40.008226 116.320738 1224729035
+40.008236 116.32081 1224729040
+40.008306 116.320851 1224729045
+40.008373 116.320857 1224729050
+40.008418 116.320872 1224729055
+40.00846 116.320864 1224729060
+40.008507 116.320918 1224729065
+40.008535 116.320997 1224729070
+40.008565 116.321079 1224729075
+40.008619 116.321168 1224729080
+40.008663 116.321249 1224729085
+40.008684 116.321297 1224729090
+40.008724 116.321395 1224729095
+40.008776 116.321462 1224729100
+40.008804 116.321504 1224729105
+40.008839 116.321516 1224729110
+40.008879 116.321555 1224729115
+40.008891 116.321571 1224729120
+40.008873 116.321615 1224729125
+40.008858 116.321677 1224729130
+40.008864 116.321766 1224729135
+40.00887 116.321858 1224729140
+40.008864 116.321933 1224729145
+40.008862 116.322009 1224729150
+40.008855 116.322087 1224729155
+40.008873 116.322144 1224729160
+40.008874 116.322234 1224729165
+40.008869 116.322306 1224729170
+40.008835 116.322331 1224729175
+40.008814 116.322311 1224729180
+40.008813 116.3223 1224729185
+40.008813 116.3223 1224729188
+40.008821 116.322297 1224729190
+40.008833 116.322297 1224729195
+40.008842 116.322298 1224729200
+40.008761 116.323722 1224729871
+40.008765 116.32369 1224729876
+40.00865 116.323112 1224729881
+40.008786 116.322741 1224729886
+40.008889 116.322551 1224729891
+40.008962 116.322462 1224729896
+40.008988 116.322356 1224729901
+40.009068 116.322259 1224729906
+40.009107 116.322177 1224729911
+40.009086 116.322088 1224729916
+40.008929 116.321992 1224729921
+40.008849 116.32191 1224729926
+40.008799 116.32185 1224729931
+40.008827 116.321784 1224729936
+40.008862 116.321736 1224729941
+40.008898 116.321695 1224729946
+40.008901 116.321684 1224729951
+40.008905 116.321683 1224729956
+40.008905 116.321683 1224729959
+40.008905 116.321682 1224729961
+40.008934 116.321623 1224729966
+40.008945 116.321551 1224729971
+40.008851 116.321485 1224731302
+40.008607 116.321862 1224731362
+40.008652 116.322251 1224731367
+40.008897 116.321603 1224731427
+40.008928 116.32161 1224731432
+40.009013 116.321431 1224731437
+40.009027 116.321399 1224731442
+40.009064 116.321326 1224731447
+40.009172 116.321211 1224731452
+40.009204 116.32113 1224731457
+40.009243 116.32105 1224731462
+40.009269 116.320978 1224731467
+40.009328 116.320887 1224731472
@@ -0,0 +1,24 @@
+//! Traces shared by the benchmarks.
+
+use trajcomp::point::Point;
+
+/// A random walk of `n` points sampled every second, from a fixed seed, with
+/// steps of up to about 5 metres in each direction.
+pub fn synthetic_trace(n: usize) -> Vec<Point> {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+    };
+    let mut lat = 39.9;
+    let mut lon = 116.3;
+    (0..n)
+        .map(|i| {
+            lat += next() * 1e-4;
+            lon += next() * 1e-4;
+            Point { lat, lon, time: i as f64, alt: None }
+        })
+        .collect()
+}
@@ -0,0 +1,81 @@
+//! Every compressor, with the parameters of its `.sh` script, on the bundled
+//! GeoLife trace and on random walks of 10^3 to 10^7 points. Throughput is
+//! reported in points per second.
+//!
+//! `cargo bench -p trajcomp --bench compressors`
+//!
+//! Set `TRAJCOMP_BENCH_MAX_POINTS` to leave out the larger random walks.
+
+use std::env;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use trajcomp::io::gpsreader;
+use trajcomp::metric::{Ped, Sed};
+use trajcomp::offline::{bottom_up, douglas_peucker, td_tr, visvalingam, VwLimit};
+use trajcomp::online::{
+    buffered_visvalingam, dead_reckoning, opw, opw_tr, squish, squish_e, sttrace, threshold, uniform,
+    velocity_dead_reckoning,
+};
+use trajcomp::point::Point;
+use trajcomp::Result;
+
+mod common;
+use common::synthetic_trace;
+
+const GEOLIFE_PLT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../online/opw/data/20081023025304-0.plt");
+const SIZES: [usize; 5] = [1_000, 10_000, 100_000, 1_000_000, 10_000_000];
+// From here on a sample takes long enough that fewer of them will do
+const LARGE: usize = 100_000;
+
+type Compressor = fn(&[Point]) -> Result<Vec<Point>>;
+
+const COMPRESSORS: [(&str, Compressor); 16] = [
+    ("uniform", |p| uniform(p, 5)),
+    ("opw", |p| opw(p, 0.0001)),
+    ("opw_tr", |p| opw_tr(p, 0.0003)),
+    ("dead_reckoning", |p| dead_reckoning(p, 0.006)),
+    ("velocity_dead_reckoning", |p| velocity_dead_reckoning(p, 10.0)),
+    ("squish", |p| squish(p, 0.2)),
+    ("squish_e_mu", |p| squish_e(p, 1.0, 0.0001)),
+    ("squish_e_lambda", |p| squish_e(p, 5.0, 0.0)),
+    ("sttrace", |p| sttrace(p, 0.2)),
+    ("threshold", |p| threshold(p, 25.0, 2, 0.8)),
+    ("douglas_peucker", |p| douglas_peucker(p, 0.0001)),
+    ("td_tr", |p| td_tr(p, 0.0003)),
+    ("visvalingam", |p| visvalingam(p, VwLimit::Points(p.len() / 5))),
+    ("buffered_visvalingam", |p| buffered_visvalingam(p, VwLimit::Points(p.len() / 5))),
+    ("bottom_up_ped", |p| bottom_up(p, Ped::default(), 0.0001)),
+    ("bottom_up_sed", |p| bottom_up(p, Sed::default(), 0.0003)),
+];
+
+fn bench_geolife(c: &mut Criterion) {
+    let points = gpsreader(GEOLIFE_PLT).expect("bundled GeoLife trace");
+    let mut group = c.benchmark_group("geolife");
+    group.throughput(Throughput::Elements(points.len() as u64));
+    for (name, compress) in COMPRESSORS {
+        group.bench_function(name, |b| b.iter(|| compress(black_box(&points))));
+    }
+    group.finish();
+}
+
+fn bench_synthetic(c: &mut Criterion) {
+    let max_points = env::var("TRAJCOMP_BENCH_MAX_POINTS").ok().and_then(|n| n.parse().ok()).unwrap_or(usize::MAX);
+    for size in SIZES.into_iter().filter(|&size| size <= max_points) {
+        let points = synthetic_trace(size);
+        let mut group = c.benchmark_group(format!("synthetic/{}", size));
+        group.throughput(Throughput::Elements(size as u64));
+        if size >= LARGE {
+            group.sample_size(10);
+        }
+        for (name, compress) in COMPRESSORS {
+            group.bench_with_input(BenchmarkId::from_parameter(name), &points, |b, points| {
+                b.iter(|| compress(black_box(points)))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_geolife, bench_synthetic);
+criterion_main!(benches);
@@ -0,0 +1,83 @@
+//! SQUISH and STTrace on a million-point trace, against the linear-scan
+//! buffer they used before the priority queue.
+//!
+//! `cargo bench -p trajcomp --bench squish`
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use trajcomp::distance::cacl_sed;
+use trajcomp::online::{OnlineCompressor, STTrace, Squish};
+use trajcomp::point::Point;
+
+mod common;
+use common::synthetic_trace;
+
+const POINTS: usize = 1_000_000;
+const BUFFER_SIZES: [usize; 3] = [100, 1_000, 10_000];
+// The linear scan is too slow to sample beyond this
+const LINEAR_MAX_BUFFER: usize = 1_000;
+
+// The buffer as it was before: find the minimum by a linear scan, then
+// `Vec::remove` it
+fn linear_scan(points: &[Point], max_buffer_size: usize, accumulate: bool) -> Vec<Point> {
+    let mut buffer: Vec<(Point, f64)> = Vec::with_capacity(max_buffer_size + 1);
+    for point in points {
+        buffer.push((*point, 0.0));
+        if buffer.len() < 3 {
+            continue;
+        }
+        let i = buffer.len() - 2;
+        buffer[i].1 += cacl_sed(&buffer[i - 1].0, &buffer[i].0, &buffer[i + 1].0);
+        if buffer.len() > max_buffer_size {
+            let mut min_index = 1;
+            for curr_i in 2..buffer.len() - 1 {
+                if buffer[curr_i].1 < buffer[min_index].1 {
+                    min_index = curr_i;
+                }
+            }
+            if accumulate {
+                let sed = buffer[min_index].1;
+                buffer[min_index - 1].1 += sed;
+                buffer[min_index + 1].1 += sed;
+            } else {
+                if min_index - 1 > 0 {
+                    buffer[min_index - 1].1 = cacl_sed(&buffer[min_index - 2].0, &buffer[min_index - 1].0, &buffer[min_index + 1].0);
+                }
+                if min_index + 1 < buffer.len() - 1 {
+                    buffer[min_index + 1].1 = cacl_sed(&buffer[min_index - 1].0, &buffer[min_index + 1].0, &buffer[min_index + 2].0);
+                }
+            }
+            buffer.remove(min_index);
+        }
+    }
+    buffer.into_iter().map(|(point, _)| point).collect()
+}
+
+fn bench_buffers(c: &mut Criterion) {
+    let points = synthetic_trace(POINTS);
+    for (name, accumulate) in [("squish", true), ("sttrace", false)] {
+        let mut group = c.benchmark_group(format!("{}/{}", name, POINTS));
+        group.sample_size(10);
+        for max_buffer_size in BUFFER_SIZES {
+            group.bench_with_input(BenchmarkId::new("heap", max_buffer_size), &max_buffer_size, |b, &size| {
+                b.iter(|| {
+                    if accumulate {
+                        Squish::new(size).compress(black_box(&points))
+                    } else {
+                        STTrace::new(size).compress(black_box(&points))
+                    }
+                })
+            });
+            if max_buffer_size <= LINEAR_MAX_BUFFER {
+                group.bench_with_input(BenchmarkId::new("linear", max_buffer_size), &max_buffer_size, |b, &size| {
+                    b.iter(|| linear_scan(black_box(&points), size, accumulate))
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_buffers);
+criterion_main!(benches);
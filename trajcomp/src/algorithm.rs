@@ -0,0 +1,233 @@
+//! A compressor and its parameters as a value, for callers that pick the
+//! algorithm at run time.
+
+use std::fmt;
+
+use crate::distance::DistanceModel;
+use crate::metric::Metric;
+use crate::offline::{bottom_up, douglas_peucker_with_model, td_tr_with_model, visvalingam_with_model, VwLimit};
+use crate::online::{
+    BufferedVisvalingam, DeadReckoning, OnlineCompressor, Opw, OpwTr, STTrace, SlidingWindow, Squish, SquishE,
+    Threshold, Uniform, VelocityDeadReckoning,
+};
+use crate::point::{check_trajectory, Point};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Algorithm {
+    /// Every `step`-th point.
+    Uniform { step: usize },
+    /// Opening window bounded by the perpendicular distance `epsilon`.
+    Opw { epsilon: f64 },
+    /// Opening window bounded by the synchronized Euclidean distance `epsilon`.
+    OpwTr { epsilon: f64 },
+    /// Dead reckoning with deviation threshold `epsilon`.
+    DeadReckoning { epsilon: f64 },
+    /// Dead reckoning keeping a point more than `epsilon` from the position
+    /// predicted by the velocity at the last kept point. `epsilon` is in
+    /// metres under every model; see [`VelocityDeadReckoning::with_model`].
+    VelocityDeadReckoning { epsilon: f64 },
+    /// SQUISH keeping the fraction `ratio` of the points.
+    Squish { ratio: f64 },
+    /// SQUISH-E(λ, μ).
+    SquishE { lambda: f64, mu: f64 },
+    /// STTrace keeping the fraction `ratio` of the points.
+    STTrace { ratio: f64 },
+    /// Threshold-guided sampling on speed (distance unit per second) and
+    /// orientation (radians) deviations.
+    Threshold { speed: f64, orientation: f64 },
+    /// Offline Douglas-Peucker bounded by the perpendicular distance `epsilon`.
+    DouglasPeucker { epsilon: f64 },
+    /// Offline TD-TR bounded by the synchronized Euclidean distance `epsilon`.
+    TdTr { epsilon: f64 },
+    /// Offline Visvalingam-Whyatt keeping the fraction `ratio` of the points.
+    Visvalingam { ratio: f64 },
+    /// Offline Visvalingam-Whyatt dropping every point with an effective
+    /// area under `area`.
+    VisvalingamArea { area: f64 },
+    /// Visvalingam-Whyatt over a SQUISH-like buffer keeping the fraction
+    /// `ratio` of the points.
+    BufferedVisvalingam { ratio: f64 },
+    /// Sliding window bounded by `epsilon` under `metric`.
+    SlidingWindow { metric: Metric, epsilon: f64 },
+    /// Offline bottom-up merging bounded by `epsilon` under `metric`.
+    BottomUp { metric: Metric, epsilon: f64 },
+}
+
+impl Algorithm {
+    /// Short name, matching the binary under `online/` or `offline/`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Uniform { .. } => "uniform",
+            Algorithm::Opw { .. } => "opw",
+            Algorithm::OpwTr { .. } => "opw_tr",
+            Algorithm::DeadReckoning { .. } => "dead_reckoning",
+            Algorithm::VelocityDeadReckoning { .. } => "velocity_dead_reckoning",
+            Algorithm::Squish { .. } => "squish",
+            Algorithm::SquishE { .. } => "squish_e",
+            Algorithm::STTrace { .. } => "sttrace",
+            Algorithm::Threshold { .. } => "threshold",
+            Algorithm::DouglasPeucker { .. } => "douglas_peucker",
+            Algorithm::TdTr { .. } => "td_tr",
+            Algorithm::Visvalingam { .. } => "visvalingam",
+            Algorithm::VisvalingamArea { .. } => "visvalingam_area",
+            Algorithm::BufferedVisvalingam { .. } => "buffered_visvalingam",
+            Algorithm::SlidingWindow { .. } => "sliding_window",
+            Algorithm::BottomUp { .. } => "bottom_up",
+        }
+    }
+
+    /// The error metric, for the algorithms that take one.
+    pub fn metric(&self) -> Option<Metric> {
+        match *self {
+            Algorithm::SlidingWindow { metric, .. } | Algorithm::BottomUp { metric, .. } => Some(metric),
+            _ => None,
+        }
+    }
+
+    /// Numeric parameter names and values, in the order of [`Algorithm`]'s
+    /// fields.
+    pub fn params(&self) -> Vec<(&'static str, f64)> {
+        match *self {
+            Algorithm::Uniform { step } => vec![("step", step as f64)],
+            Algorithm::Opw { epsilon }
+            | Algorithm::OpwTr { epsilon }
+            | Algorithm::DeadReckoning { epsilon }
+            | Algorithm::VelocityDeadReckoning { epsilon }
+            | Algorithm::DouglasPeucker { epsilon }
+            | Algorithm::TdTr { epsilon }
+            | Algorithm::SlidingWindow { epsilon, .. }
+            | Algorithm::BottomUp { epsilon, .. } => vec![("epsilon", epsilon)],
+            Algorithm::Squish { ratio }
+            | Algorithm::STTrace { ratio }
+            | Algorithm::Visvalingam { ratio }
+            | Algorithm::BufferedVisvalingam { ratio } => vec![("ratio", ratio)],
+            Algorithm::VisvalingamArea { area } => vec![("area", area)],
+            Algorithm::SquishE { lambda, mu } => vec![("lambda", lambda), ("mu", mu)],
+            Algorithm::Threshold { speed, orientation } => vec![("speed", speed), ("orientation", orientation)],
+        }
+    }
+
+    /// The same algorithm with its error bound set to `epsilon`, or `None`
+    /// if it is not bounded by an epsilon.
+    pub fn with_epsilon(&self, epsilon: f64) -> Option<Algorithm> {
+        match *self {
+            Algorithm::Opw { .. } => Some(Algorithm::Opw { epsilon }),
+            Algorithm::OpwTr { .. } => Some(Algorithm::OpwTr { epsilon }),
+            Algorithm::DeadReckoning { .. } => Some(Algorithm::DeadReckoning { epsilon }),
+            Algorithm::VelocityDeadReckoning { .. } => Some(Algorithm::VelocityDeadReckoning { epsilon }),
+            Algorithm::DouglasPeucker { .. } => Some(Algorithm::DouglasPeucker { epsilon }),
+            Algorithm::TdTr { .. } => Some(Algorithm::TdTr { epsilon }),
+            Algorithm::VisvalingamArea { .. } => Some(Algorithm::VisvalingamArea { area: epsilon }),
+            Algorithm::SlidingWindow { metric, .. } => Some(Algorithm::SlidingWindow { metric, epsilon }),
+            Algorithm::BottomUp { metric, .. } => Some(Algorithm::BottomUp { metric, epsilon }),
+            _ => None,
+        }
+    }
+
+    /// Checks that the parameters are in range.
+    pub fn validate(&self) -> Result<(), String> {
+        let non_negative = |name: &str, value: f64| {
+            if value >= 0.0 {
+                Ok(())
+            } else {
+                Err(format!("{} must be a non-negative number, got {}", name, value))
+            }
+        };
+        match *self {
+            Algorithm::Uniform { step: 0 } => Err("step must be at least 1".to_string()),
+            Algorithm::Uniform { .. } => Ok(()),
+            Algorithm::Opw { epsilon }
+            | Algorithm::OpwTr { epsilon }
+            | Algorithm::DeadReckoning { epsilon }
+            | Algorithm::VelocityDeadReckoning { epsilon }
+            | Algorithm::DouglasPeucker { epsilon }
+            | Algorithm::TdTr { epsilon }
+            | Algorithm::SlidingWindow { epsilon, .. }
+            | Algorithm::BottomUp { epsilon, .. } => non_negative("epsilon", epsilon),
+            Algorithm::Squish { ratio }
+            | Algorithm::STTrace { ratio }
+            | Algorithm::Visvalingam { ratio }
+            | Algorithm::BufferedVisvalingam { ratio } => {
+                if ratio > 0.0 && ratio <= 1.0 {
+                    Ok(())
+                } else {
+                    Err(format!("ratio must be in (0, 1], got {}", ratio))
+                }
+            }
+            Algorithm::SquishE { lambda, mu } => {
+                if lambda >= 1.0 {
+                    non_negative("mu", mu)
+                } else {
+                    Err(format!("lambda must be at least 1, got {}", lambda))
+                }
+            }
+            Algorithm::Threshold { speed, orientation } => {
+                non_negative("speed", speed)?;
+                non_negative("orientation", orientation)
+            }
+            Algorithm::VisvalingamArea { area } => non_negative("area", area),
+        }
+    }
+
+    /// Compresses `points`, measuring distances with `model`. Trajectories
+    /// of up to two points are returned as they are; empty or unordered ones
+    /// are an error.
+    pub fn compress(&self, points: &[Point], model: DistanceModel) -> crate::Result<Vec<Point>> {
+        check_trajectory(points)?;
+        if points.len() < 3 {
+            return Ok(points.to_vec());
+        }
+        match *self {
+            Algorithm::Uniform { step } => Uniform::new(step).compress(points),
+            Algorithm::Opw { epsilon } => Opw::new(epsilon).with_model(model).compress(points),
+            Algorithm::OpwTr { epsilon } => OpwTr::new(epsilon).with_model(model).compress(points),
+            Algorithm::DeadReckoning { epsilon } => DeadReckoning::new(epsilon).with_model(model).compress(points),
+            Algorithm::VelocityDeadReckoning { epsilon } => {
+                VelocityDeadReckoning::new(epsilon).with_model(model).compress(points)
+            }
+            Algorithm::Squish { ratio } => {
+                let max_buffer_size = (ratio * points.len() as f64) as usize;
+                Squish::new(max_buffer_size).with_model(model).compress(points)
+            }
+            Algorithm::SquishE { lambda, mu } => SquishE::new(lambda, mu).with_model(model).compress(points),
+            Algorithm::STTrace { ratio } => {
+                let max_buffer_size = (ratio * points.len() as f64) as usize;
+                STTrace::new(max_buffer_size).with_model(model).compress(points)
+            }
+            Algorithm::Threshold { speed, orientation } => {
+                Threshold::new(speed, 0, orientation).with_model(model).compress(points)
+            }
+            Algorithm::DouglasPeucker { epsilon } => douglas_peucker_with_model(points, epsilon, model),
+            Algorithm::TdTr { epsilon } => td_tr_with_model(points, epsilon, model),
+            Algorithm::Visvalingam { ratio } => {
+                let max_points = (ratio * points.len() as f64) as usize;
+                visvalingam_with_model(points, VwLimit::Points(max_points), model)
+            }
+            Algorithm::VisvalingamArea { area } => visvalingam_with_model(points, VwLimit::Area(area), model),
+            Algorithm::BufferedVisvalingam { ratio } => {
+                let max_points = (ratio * points.len() as f64) as usize;
+                BufferedVisvalingam::new(VwLimit::Points(max_points)).with_model(model).compress(points)
+            }
+            Algorithm::SlidingWindow { metric, epsilon } => {
+                SlidingWindow::with_metric(metric.with_model(model), epsilon).compress(points)
+            }
+            Algorithm::BottomUp { metric, epsilon } => bottom_up(points, metric.with_model(model), epsilon),
+        }
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.name())?;
+        if let Some(metric) = self.metric() {
+            write!(f, "metric={}, ", metric)?;
+        }
+        for (i, (name, value)) in self.params().iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}={}", name, value)?;
+        }
+        write!(f, ")")
+    }
+}
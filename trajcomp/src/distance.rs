@@ -0,0 +1,365 @@
+//! Distance primitives shared by the compressors.
+//!
+//! The `cacl_*` functions treat coordinates as planar degrees;
+//! [`DistanceModel`] selects between those and metre based models.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::point::Point;
+
+const EARTH_RADIUS: i32 = 6371229;
+const M_PI: f64 = std::f64::consts::PI;
+
+/// Euclidean distance between two points.
+pub fn cacl_distance(a: &Point, b: &Point) -> f64 {
+    ((a.lat - b.lat).powi(2) + (a.lon - b.lon).powi(2)).sqrt()
+}
+
+/// Great-circle distance between two points in metres.
+pub fn cacl_haversine(a: &Point, b: &Point) -> f64 {
+    let lat1 = a.lat * M_PI / 180.0;
+    let lat2 = b.lat * M_PI / 180.0;
+    let lon1 = a.lon * M_PI / 180.0;
+    let lon2 = b.lon * M_PI / 180.0;
+    let dlat = lat2 - lat1;
+    let dlon = lon2 - lon1;
+    let a_a = ((dlat / 2.0).sin()).powi(2) + ((dlon / 2.0).sin()).powi(2) * lat1.cos() * lat2.cos();
+    let c = 2.0 * (((a_a).sqrt()).atan2((1.0 - a_a).sqrt()));
+    (EARTH_RADIUS as f64) * c
+}
+
+/// Heading of the segment `a -> b`, measured from the latitude axis.
+pub fn cacl_angle(a: &Point, b: &Point) -> f64 {
+    let lat_diff = b.lat - a.lat;
+    let lon_diff = b.lon - a.lon;
+    lon_diff.atan2(lat_diff)
+}
+
+/// Perpendicular distance of `m` from the line through `s` and `e`, or from
+/// `s` when the two coincide, as the other distance models measure it.
+pub fn cacl_ped(s: &Point, m: &Point, e: &Point) -> f64 {
+    let a: f64 = e.lon - s.lon;
+    let b: f64 = s.lat - e.lat;
+    let c: f64 = e.lat * s.lon - s.lat * e.lon;
+    if (a == 0.0) && (b == 0.0) {
+        return (m.lat - s.lat).hypot(m.lon - s.lon);
+    }
+    let short_dist: f64 = ((a * m.lat + b * m.lon + c) / (a.powi(2) + b.powi(2)).sqrt()).abs();
+    short_dist
+}
+
+/// Synchronized Euclidean distance: the distance between `m` and the point
+/// on `s -> e` at the same time, assuming constant speed along the segment.
+pub fn cacl_sed(s: &Point, m: &Point, e: &Point) -> f64 {
+    let numerator = m.time - s.time;
+    let denominator = e.time - s.time;
+    let time_ratio = if denominator != 0.0 {
+        numerator / denominator
+    } else {
+        1.0
+    };
+    let lat = s.lat + (e.lat - s.lat) * time_ratio;
+    let lon = s.lon + (e.lon - s.lon) * time_ratio;
+    let lat_diff = lat - m.lat;
+    let lon_diff = lon - m.lon;
+    (lat_diff.powi(2) + lon_diff.powi(2)).sqrt()
+}
+
+/// Area of the triangle `a`, `b`, `c`, in square degrees.
+pub fn cacl_area(a: &Point, b: &Point, c: &Point) -> f64 {
+    ((b.lat - a.lat) * (c.lon - a.lon) - (c.lat - a.lat) * (b.lon - a.lon)).abs() / 2.0
+}
+
+// WGS 84 ellipsoid
+const WGS84_A: f64 = 6378137.0;
+const WGS84_F: f64 = 1.0 / 298.257223563;
+
+/// How distances between coordinates are measured.
+///
+/// `Planar` treats degrees as plane coordinates, which is what the
+/// `cacl_*` functions above do and what the tolerances of the bundled
+/// scripts are expressed in. The other models work in metres, so a
+/// tolerance means the same thing at every latitude.
+///
+/// For the segment based errors, `s`/`e` positions are interpolated linearly
+/// in latitude and longitude, as [`cacl_sed`] does, and the metre models
+/// measure the perpendicular distance in a plane tangent at `s`.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DistanceModel {
+    /// Degrees as plane coordinates.
+    #[default]
+    Planar,
+    /// Local equirectangular projection on a sphere, in metres.
+    Equirectangular,
+    /// Great-circle distance on a sphere, in metres.
+    Haversine,
+    /// Geodesic distance on the WGS 84 ellipsoid (Vincenty), in metres.
+    Ellipsoidal,
+}
+
+impl DistanceModel {
+    pub const ALL: [DistanceModel; 4] = [
+        DistanceModel::Planar,
+        DistanceModel::Equirectangular,
+        DistanceModel::Haversine,
+        DistanceModel::Ellipsoidal,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DistanceModel::Planar => "planar",
+            DistanceModel::Equirectangular => "equirectangular",
+            DistanceModel::Haversine => "haversine",
+            DistanceModel::Ellipsoidal => "ellipsoidal",
+        }
+    }
+
+    /// Distance between two points.
+    pub fn distance(&self, a: &Point, b: &Point) -> f64 {
+        match self {
+            DistanceModel::Planar => cacl_distance(a, b),
+            DistanceModel::Equirectangular => {
+                let (north, east) = self.project(a, b);
+                north.hypot(east)
+            }
+            DistanceModel::Haversine => cacl_haversine(a, b),
+            DistanceModel::Ellipsoidal => vincenty(a, b).unwrap_or_else(|| cacl_haversine(a, b)),
+        }
+    }
+
+    /// Heading of the segment `a -> b`, measured from north towards east.
+    pub fn heading(&self, a: &Point, b: &Point) -> f64 {
+        match self {
+            DistanceModel::Planar => cacl_angle(a, b),
+            DistanceModel::Haversine => {
+                let (lat1, lat2) = (a.lat.to_radians(), b.lat.to_radians());
+                let dlon = (b.lon - a.lon).to_radians();
+                let y = dlon.sin() * lat2.cos();
+                let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+                y.atan2(x)
+            }
+            _ => {
+                let (north, east) = self.project(a, b);
+                east.atan2(north)
+            }
+        }
+    }
+
+    /// Perpendicular distance of `m` from the line through `s` and `e`.
+    pub fn ped(&self, s: &Point, m: &Point, e: &Point) -> f64 {
+        match self {
+            DistanceModel::Planar => cacl_ped(s, m, e),
+            DistanceModel::Haversine => {
+                // Cross-track distance from the great circle through s and e
+                if s.lat == e.lat && s.lon == e.lon {
+                    return cacl_haversine(s, m);
+                }
+                let radius = EARTH_RADIUS as f64;
+                let d_sm = cacl_haversine(s, m) / radius;
+                let bearing = self.heading(s, m) - self.heading(s, e);
+                ((d_sm.sin() * bearing.sin()).asin() * radius).abs()
+            }
+            _ => {
+                let (m_north, m_east) = self.project(s, m);
+                let (e_north, e_east) = self.project(s, e);
+                let length = e_north.hypot(e_east);
+                if length == 0.0 {
+                    return m_north.hypot(m_east);
+                }
+                (e_east * m_north - e_north * m_east).abs() / length
+            }
+        }
+    }
+
+    /// Synchronized Euclidean distance of `m` from the segment `s -> e`.
+    pub fn sed(&self, s: &Point, m: &Point, e: &Point) -> f64 {
+        match self {
+            DistanceModel::Planar => cacl_sed(s, m, e),
+            _ => self.distance(&interpolate(s, e, m.time), m),
+        }
+    }
+
+    /// Area of the triangle `a`, `b`, `c`. The metre models give square
+    /// metres, in a plane tangent at `a`.
+    pub fn area(&self, a: &Point, b: &Point, c: &Point) -> f64 {
+        match self {
+            DistanceModel::Planar => cacl_area(a, b, c),
+            _ => {
+                let (b_north, b_east) = self.project(a, b);
+                let (c_north, c_east) = self.project(a, c);
+                (b_north * c_east - c_north * b_east).abs() / 2.0
+            }
+        }
+    }
+
+    // Offset of `p` from `origin` as (north, east), in metres
+    fn project(&self, origin: &Point, p: &Point) -> (f64, f64) {
+        let dlat = (p.lat - origin.lat).to_radians();
+        let dlon = (p.lon - origin.lon).to_radians();
+        match self {
+            DistanceModel::Ellipsoidal => {
+                // Meridian and prime vertical radii of curvature at the origin
+                let e2 = WGS84_F * (2.0 - WGS84_F);
+                let lat = origin.lat.to_radians();
+                let w = (1.0 - e2 * lat.sin().powi(2)).sqrt();
+                let meridian = WGS84_A * (1.0 - e2) / w.powi(3);
+                let prime_vertical = WGS84_A / w;
+                (dlat * meridian, dlon * prime_vertical * lat.cos())
+            }
+            _ => {
+                let radius = EARTH_RADIUS as f64;
+                let lat = ((origin.lat + p.lat) / 2.0).to_radians();
+                (dlat * radius, dlon * radius * lat.cos())
+            }
+        }
+    }
+}
+
+impl fmt::Display for DistanceModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for DistanceModel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DistanceModel::ALL
+            .into_iter()
+            .find(|model| model.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown distance model {:?}, expected one of planar, equirectangular, haversine, ellipsoidal", s))
+    }
+}
+
+/// Position on `s -> e` at time `time`, assuming constant speed, with the
+/// same time ratio as [`cacl_sed`].
+pub fn interpolate(s: &Point, e: &Point, time: f64) -> Point {
+    let denominator = e.time - s.time;
+    let time_ratio = if denominator != 0.0 {
+        (time - s.time) / denominator
+    } else {
+        1.0
+    };
+    Point {
+        lat: s.lat + (e.lat - s.lat) * time_ratio,
+        lon: s.lon + (e.lon - s.lon) * time_ratio,
+        time,
+        alt: match (s.alt, e.alt) {
+            (Some(a), Some(b)) => Some(a + (b - a) * time_ratio),
+            _ => None,
+        },
+    }
+}
+
+// Vincenty's inverse formula, `None` when it does not converge (nearly
+// antipodal points)
+fn vincenty(a: &Point, b: &Point) -> Option<f64> {
+    let b_axis = WGS84_A * (1.0 - WGS84_F);
+    let l = (b.lon - a.lon).to_radians();
+    let u1 = ((1.0 - WGS84_F) * a.lat.to_radians().tan()).atan();
+    let u2 = ((1.0 - WGS84_F) * b.lat.to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    for _ in 0..200 {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2) + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2)).sqrt();
+        if sin_sigma == 0.0 {
+            return Some(0.0);
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos2_alpha = 1.0 - sin_alpha.powi(2);
+        let cos_2sigma_m = if cos2_alpha != 0.0 {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha
+        } else {
+            0.0
+        };
+        let c = WGS84_F / 16.0 * cos2_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos2_alpha));
+        let previous = lambda;
+        lambda = l + (1.0 - c) * WGS84_F * sin_alpha
+            * (sigma + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+        if (lambda - previous).abs() < 1e-12 {
+            let u_sq = cos2_alpha * (WGS84_A.powi(2) - b_axis.powi(2)) / b_axis.powi(2);
+            let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+            let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+            let delta_sigma = big_b * sin_sigma * (cos_2sigma_m + big_b / 4.0
+                * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))
+                    - big_b / 6.0 * cos_2sigma_m * (-3.0 + 4.0 * sin_sigma.powi(2)) * (-3.0 + 4.0 * cos_2sigma_m.powi(2))));
+            return Some(b_axis * big_a * (sigma - delta_sigma));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lon: f64) -> Point {
+        Point { lat, lon, time: 0.0, alt: None }
+    }
+
+    // Metres in one degree of a great circle
+    fn degree() -> f64 {
+        (EARTH_RADIUS as f64).to_radians()
+    }
+
+    #[test]
+    fn vincenty_matches_known_geodesics() {
+        // Flinders Peak to Buninyong, from Vincenty's paper as checked by
+        // Geoscience Australia
+        let flinders_peak = point(-(37.0 + 57.0 / 60.0 + 3.72030 / 3600.0), 144.0 + 25.0 / 60.0 + 29.52440 / 3600.0);
+        let buninyong = point(-(37.0 + 39.0 / 60.0 + 10.15610 / 3600.0), 143.0 + 55.0 / 60.0 + 35.38390 / 3600.0);
+        let distance = DistanceModel::Ellipsoidal.distance(&flinders_peak, &buninyong);
+        assert!((distance - 54_972.271).abs() < 1e-3, "{}", distance);
+        // A quarter meridian
+        let distance = DistanceModel::Ellipsoidal.distance(&point(0.0, 0.0), &point(90.0, 0.0));
+        assert!((distance - 10_001_965.729).abs() < 1e-3, "{}", distance);
+    }
+
+    #[test]
+    fn near_antipodal_points_fall_back_to_a_finite_distance() {
+        // Vincenty does not converge here; the geodesic is 19 944 127.421 m
+        let (a, b) = (point(0.0, 0.0), point(0.5, 179.7));
+        assert_eq!(vincenty(&a, &b), None);
+        let distance = DistanceModel::Ellipsoidal.distance(&a, &b);
+        assert!(distance.is_finite());
+        assert!((distance / 19_944_127.421 - 1.0).abs() < 0.01, "{}", distance);
+    }
+
+    #[test]
+    fn coincident_points_are_zero_apart() {
+        let p = point(39.98, 116.31);
+        for model in DistanceModel::ALL {
+            assert_eq!(model.distance(&p, &p), 0.0, "{}", model);
+            assert_eq!(model.ped(&p, &p, &p), 0.0, "{}", model);
+            assert_eq!(model.sed(&p, &p, &p), 0.0, "{}", model);
+        }
+        // A degenerate segment measures from its start
+        let m = point(40.98, 116.31);
+        assert!((DistanceModel::Haversine.ped(&p, &m, &p) - degree()).abs() < 1e-6);
+        assert!((DistanceModel::Equirectangular.ped(&p, &m, &p) - degree()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn equirectangular_shrinks_longitude_with_latitude() {
+        let model = DistanceModel::Equirectangular;
+        assert!((model.distance(&point(0.0, 0.0), &point(1.0, 0.0)) - degree()).abs() < 1e-6);
+        assert!((model.distance(&point(0.0, 0.0), &point(0.0, 1.0)) - degree()).abs() < 1e-6);
+        assert!((model.distance(&point(60.0, 0.0), &point(60.0, 1.0)) - degree() / 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cross_track_ped_from_the_equator() {
+        let (s, m, e) = (point(0.0, 0.0), point(1.0, 5.0), point(0.0, 10.0));
+        assert!((DistanceModel::Haversine.ped(&s, &m, &e) - degree()).abs() < 1e-6);
+        assert!((DistanceModel::Haversine.ped(&s, &point(-1.0, 5.0), &e) - degree()).abs() < 1e-6);
+        assert!(DistanceModel::Haversine.ped(&s, &point(0.0, 5.0), &e) < 1e-6);
+        assert_eq!(DistanceModel::Planar.ped(&s, &m, &e), 1.0);
+    }
+}
@@ -0,0 +1,101 @@
+//! Errors returned by the readers and the compressors.
+
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// A record could not be parsed. `column` is the 1-based field for the
+    /// delimited formats and the character column for GPX.
+    MalformedLine { line: usize, column: usize, message: String },
+    /// Point number `point` (1-based) is earlier than the point before it.
+    NonMonotonicTime { point: usize, previous: f64, time: f64 },
+    /// The trajectory has no points.
+    EmptyTrajectory,
+    /// `error` occurred in the trajectory of object `id`.
+    Object { id: String, error: Box<Error> },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::MalformedLine { line, column, message } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::NonMonotonicTime { point, previous, time } => {
+                write!(f, "time goes backwards at point {}: {} after {}", point, time, previous)
+            }
+            Error::EmptyTrajectory => write!(f, "trajectory has no points"),
+            Error::Object { id, error } => write!(f, "object {}: {}", id, error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Object { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+// Records that cannot be read are malformed lines, with the 1-based field
+// where known; anything else stays an I/O error
+impl From<csv::Error> for Error {
+    fn from(e: csv::Error) -> Self {
+        let line = e.position().map_or(0, |position| position.line() as usize);
+        let (column, message) = match e.kind() {
+            csv::ErrorKind::Utf8 { err, .. } => (err.field() + 1, err.to_string()),
+            csv::ErrorKind::Deserialize { err, .. } => {
+                (err.field().map_or(1, |field| field as usize + 1), err.kind().to_string())
+            }
+            csv::ErrorKind::UnequalLengths { expected_len, len, .. } => (
+                (*expected_len).min(*len) as usize + 1,
+                format!("{} fields, but the previous record has {}", len, expected_len),
+            ),
+            _ => return Error::Io(e.into()),
+        };
+        Error::MalformedLine { line, column, message }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reader(csv: &[u8]) -> csv::Reader<&[u8]> {
+        csv::ReaderBuilder::new().has_headers(false).from_reader(csv)
+    }
+
+    #[test]
+    fn unreadable_records_are_malformed_lines() {
+        let records: std::result::Result<Vec<(f64, f64)>, _> = reader(b"1,2\n3,x\n").deserialize().collect();
+        let error = Error::from(records.unwrap_err());
+        assert!(matches!(error, Error::MalformedLine { line: 2, column: 2, .. }), "{}", error);
+
+        let records: std::result::Result<Vec<_>, _> = reader(b"1,2\n3,4,5\n").records().collect();
+        let error = Error::from(records.unwrap_err());
+        assert!(matches!(error, Error::MalformedLine { line: 2, column: 3, .. }), "{}", error);
+
+        let records: std::result::Result<Vec<_>, _> = reader(b"1,2\n3,\xff\n").records().collect();
+        let error = Error::from(records.unwrap_err());
+        assert!(matches!(error, Error::MalformedLine { line: 2, column: 2, .. }), "{}", error);
+    }
+
+    #[test]
+    fn failed_reads_stay_io_errors() {
+        let error = Error::from(csv::Error::from(io::Error::other("disk on fire")));
+        assert!(matches!(error, Error::Io(ref e) if e.to_string() == "disk on fire"), "{}", error);
+    }
+}
@@ -0,0 +1,155 @@
+//! Error introduced by a compressor, measured on every original point
+//! against the compressed segment that covers its timestamp.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::distance::DistanceModel;
+use crate::metric::{ErrorMetric, Ped, Sed};
+use crate::point::Point;
+
+/// Maximum, mean and root mean square of a per-point error.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Serialize)]
+pub struct ErrorStats {
+    pub max: f64,
+    pub mean: f64,
+    pub rms: f64,
+}
+
+impl ErrorStats {
+    /// Statistics over several sets of errors, given with the number of
+    /// errors in each, as if they had been measured together.
+    pub fn combine(stats: &[(ErrorStats, usize)]) -> Self {
+        let count: usize = stats.iter().map(|(_, count)| count).sum();
+        if count == 0 {
+            return ErrorStats::default();
+        }
+        let n = count as f64;
+        ErrorStats {
+            max: stats.iter().map(|(stats, _)| stats.max).fold(0.0, f64::max),
+            mean: stats.iter().map(|(stats, count)| stats.mean * *count as f64).sum::<f64>() / n,
+            rms: (stats.iter().map(|(stats, count)| stats.rms.powi(2) * *count as f64).sum::<f64>() / n).sqrt(),
+        }
+    }
+
+    fn from_errors(errors: &[f64]) -> Self {
+        if errors.is_empty() {
+            return ErrorStats::default();
+        }
+        let n = errors.len() as f64;
+        ErrorStats {
+            max: errors.iter().cloned().fold(0.0, f64::max),
+            mean: errors.iter().sum::<f64>() / n,
+            rms: (errors.iter().map(|e| e * e).sum::<f64>() / n).sqrt(),
+        }
+    }
+}
+
+/// Quality of a compressed trajectory compared to the original one.
+///
+/// `compression_ratio` is the fraction of points kept, the same quantity as
+/// the `cmp_ratio` parameter of SQUISH and STTrace.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub original_points: usize,
+    pub compressed_points: usize,
+    pub compression_ratio: f64,
+    pub sed: ErrorStats,
+    pub ped: ErrorStats,
+}
+
+impl Report {
+    /// Report over several trajectories, as if the errors of all their
+    /// points had been measured together.
+    pub fn combine(reports: &[Report]) -> Report {
+        let original_points: usize = reports.iter().map(|r| r.original_points).sum();
+        let compressed_points: usize = reports.iter().map(|r| r.compressed_points).sum();
+        let combine_stats = |stats: fn(&Report) -> ErrorStats| {
+            let stats: Vec<(ErrorStats, usize)> = reports.iter().map(|r| (stats(r), r.original_points)).collect();
+            ErrorStats::combine(&stats)
+        };
+        Report {
+            original_points,
+            compressed_points,
+            compression_ratio: if original_points == 0 { 0.0 } else { compressed_points as f64 / original_points as f64 },
+            sed: combine_stats(|r| r.sed),
+            ped: combine_stats(|r| r.ped),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "points: {} -> {} (compression ratio {:.4})", self.original_points, self.compressed_points, self.compression_ratio)?;
+        writeln!(f, "SED: max {:.6e}, mean {:.6e}, rms {:.6e}", self.sed.max, self.sed.mean, self.sed.rms)?;
+        write!(f, "PED: max {:.6e}, mean {:.6e}, rms {:.6e}", self.ped.max, self.ped.mean, self.ped.rms)
+    }
+}
+
+/// Measures the SED and PED of every original point from the compressed
+/// segment spanning its timestamp, in planar degrees.
+pub fn evaluate(original: &[Point], compressed: &[Point]) -> Report {
+    evaluate_with_model(original, compressed, DistanceModel::Planar)
+}
+
+/// [`evaluate`] with errors measured by `model`.
+pub fn evaluate_with_model(original: &[Point], compressed: &[Point], model: DistanceModel) -> Report {
+    let compression_ratio = if original.is_empty() {
+        0.0
+    } else {
+        compressed.len() as f64 / original.len() as f64
+    };
+    Report {
+        original_points: original.len(),
+        compressed_points: compressed.len(),
+        compression_ratio,
+        sed: evaluate_metric(original, compressed, Sed(model)),
+        ped: evaluate_metric(original, compressed, Ped(model)),
+    }
+}
+
+/// Measures every original point under `metric` against the compressed
+/// segment spanning its timestamp, within the original points that segment
+/// replaces. A single compressed point stands for a segment from that point
+/// to itself.
+pub fn evaluate_metric<M: ErrorMetric>(original: &[Point], compressed: &[Point], metric: M) -> ErrorStats {
+    let mut errors = Vec::<f64>::with_capacity(original.len());
+
+    if compressed.len() == 1 {
+        for i in 0..original.len() {
+            errors.push(metric.span_error(&compressed[0], &compressed[0], original, i));
+        }
+    } else if compressed.len() > 1 {
+        // Both trajectories are in time order, so the covering segment only
+        // moves forward
+        let mut k = 0;
+        let covering: Vec<usize> = original
+            .iter()
+            .map(|point| {
+                while k + 2 < compressed.len() && compressed[k + 1].time < point.time {
+                    k += 1;
+                }
+                k
+            })
+            .collect();
+        // A segment replaces the points it covers and the one before them
+        let mut start = 0;
+        while start < original.len() {
+            let k = covering[start];
+            let end = start + covering[start..].iter().take_while(|&&covered_by| covered_by == k).count();
+            let from = start.saturating_sub(1);
+            let span = &original[from..end];
+            for i in start..end {
+                errors.push(metric.span_error(&compressed[k], &compressed[k + 1], span, i - from));
+            }
+            start = end;
+        }
+    }
+
+    ErrorStats::from_errors(&errors)
+}
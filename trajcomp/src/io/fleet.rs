@@ -0,0 +1,215 @@
+//! CSV files that interleave the trajectories of many objects, one
+//! `id,lat,lon,time` record per point.
+//!
+//! A header row is optional. With one, the columns are found by name
+//! (`id`/`object_id`/`vehicle_id`, `lat`/`latitude`,
+//! `lon`/`lng`/`longitude` and `time`/`timestamp`) and may come in any
+//! order.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::io::parse_field;
+use crate::parallel::parallel_map;
+use crate::point::Point;
+
+/// The trajectory of one object, in time order.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ObjectTrack {
+    pub id: String,
+    pub points: Vec<Point>,
+}
+
+// Positions of the id, lat, lon and time columns
+#[derive(Debug, Copy, Clone)]
+struct Columns {
+    id: usize,
+    lat: usize,
+    lon: usize,
+    time: usize,
+}
+
+const DEFAULT_COLUMNS: Columns = Columns { id: 0, lat: 1, lon: 2, time: 3 };
+
+const ID_NAMES: [&str; 3] = ["id", "object_id", "vehicle_id"];
+
+impl Columns {
+    fn from_header(header: &csv::StringRecord, line: usize) -> Result<Columns> {
+        let find = |names: &[&str]| {
+            header
+                .iter()
+                .position(|field| names.iter().any(|name| field.trim().eq_ignore_ascii_case(name)))
+                .ok_or_else(|| Error::MalformedLine { line, column: 1, message: format!("no {} column", names[0]) })
+        };
+        Ok(Columns {
+            id: find(&ID_NAMES)?,
+            lat: find(&["lat", "latitude"])?,
+            lon: find(&["lon", "lng", "longitude"])?,
+            time: find(&["time", "timestamp"])?,
+        })
+    }
+}
+
+/// Whether `line` is the header row of a fleet CSV file, naming an id column.
+/// Without a header, a fleet file cannot be told from a CSV with extra
+/// columns.
+pub fn is_fleet_header(line: &str) -> bool {
+    line.split(',').any(|field| ID_NAMES.iter().any(|name| field.trim().trim_matches('"').eq_ignore_ascii_case(name)))
+}
+
+/// Reads a fleet CSV file.
+pub fn read_fleet_csv<P: AsRef<Path>>(path: P) -> Result<Vec<ObjectTrack>> {
+    parse_fleet_csv(File::open(path)?)
+}
+
+/// Parses fleet CSV records and groups them by object, in order of first
+/// appearance. Each object's points must be in time order.
+pub fn parse_fleet_csv<R: Read>(reader: R) -> Result<Vec<ObjectTrack>> {
+    let mut reader = csv::ReaderBuilder::new().has_headers(false).flexible(true).from_reader(reader);
+    let mut tracks: Vec<ObjectTrack> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut columns = DEFAULT_COLUMNS;
+    for (row, record) in reader.records().enumerate() {
+        let record = record?;
+        let line = record.position().map_or(0, |position| position.line() as usize);
+        // A first row without a number where the latitude goes is a header
+        if row == 0 && record.get(1).is_none_or(|lat| lat.trim().parse::<f64>().is_err()) {
+            columns = Columns::from_header(&record, line)?;
+            continue;
+        }
+        let id = record.get(columns.id).map(str::trim).ok_or_else(|| Error::MalformedLine {
+            line,
+            column: columns.id + 1,
+            message: "missing field".to_string(),
+        })?;
+        let point = Point {
+            lat: parse_field(record.get(columns.lat), line, columns.lat + 1)?,
+            lon: parse_field(record.get(columns.lon), line, columns.lon + 1)?,
+            time: parse_field(record.get(columns.time), line, columns.time + 1)?,
+            ..Default::default()
+        };
+        let i = *index.entry(id.to_string()).or_insert_with(|| {
+            tracks.push(ObjectTrack { id: id.to_string(), points: Vec::new() });
+            tracks.len() - 1
+        });
+        let points = &mut tracks[i].points;
+        if let Some(previous) = points.last() {
+            if point.time < previous.time {
+                return Err(Error::MalformedLine {
+                    line,
+                    column: columns.time + 1,
+                    message: format!("time of object {:?} goes backwards: {} after {}", id, point.time, previous.time),
+                });
+            }
+        }
+        points.push(point);
+    }
+    if tracks.is_empty() {
+        return Err(Error::EmptyTrajectory);
+    }
+    Ok(tracks)
+}
+
+/// Writes tracks to a fleet CSV file.
+pub fn write_fleet_csv<P: AsRef<Path>>(tracks: &[ObjectTrack], path: P) -> Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_fleet_csv_to(tracks, &mut writer)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes headerless `id,lat,lon,time` records, one object after the other.
+pub fn write_fleet_csv_to<W: Write>(tracks: &[ObjectTrack], writer: W) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+    for track in tracks {
+        for point in &track.points {
+            writer.write_record([track.id.clone(), point.lat.to_string(), point.lon.to_string(), point.time.to_string()])?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Applies `compress` to every object on its own, spreading the objects over
+/// all available cores when `parallel` is set. Fails with the error of the
+/// first failing object, wrapped in [`Error::Object`].
+pub fn compress_fleet<F>(tracks: &[ObjectTrack], compress: F, parallel: bool) -> Result<Vec<ObjectTrack>>
+where F: Fn(&[Point]) -> Result<Vec<Point>> + Sync, {
+    let compressed = compress_fleet_with(tracks, |points| compress(points).map(|points| (points, ())), parallel)?;
+    Ok(compressed.into_iter().map(|(track, ())| track).collect())
+}
+
+/// Like [`compress_fleet`], for a `compress` that also returns a value per
+/// object, such as the parameter it settled on. The values come back with
+/// their objects, in input order.
+pub fn compress_fleet_with<F, T>(tracks: &[ObjectTrack], compress: F, parallel: bool) -> Result<Vec<(ObjectTrack, T)>>
+where
+    F: Fn(&[Point]) -> Result<(Vec<Point>, T)> + Sync,
+    T: Send, {
+    let compress_track = |track: &ObjectTrack| {
+        compress(&track.points)
+            .map(|(points, value)| (ObjectTrack { id: track.id.clone(), points }, value))
+            .map_err(|error| Error::Object { id: track.id.clone(), error: Box::new(error) })
+    };
+    if parallel {
+        parallel_map(tracks, 0, compress_track).into_iter().collect()
+    } else {
+        tracks.iter().map(compress_track).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lon: f64, time: f64) -> Point {
+        Point { lat, lon, time, alt: None }
+    }
+
+    #[test]
+    fn detects_a_header_by_its_id_column() {
+        assert!(is_fleet_header("id,lat,lon,time"));
+        assert!(is_fleet_header("Timestamp, Latitude, Longitude, \"Vehicle_ID\""));
+        assert!(!is_fleet_header("a,40,116,0"));
+        assert!(!is_fleet_header("lat,lon,time,alt"));
+    }
+
+    #[test]
+    fn groups_interleaved_objects_in_order_of_appearance() {
+        let csv = "b,40,116,0\na,41,117,0\nb,40.5,116.5,1\na,41.5,117.5,2\nb,40.7,116.7,3\n";
+        let tracks = parse_fleet_csv(csv.as_bytes()).unwrap();
+        assert_eq!(
+            tracks,
+            [
+                ObjectTrack {
+                    id: "b".to_string(),
+                    points: vec![point(40.0, 116.0, 0.0), point(40.5, 116.5, 1.0), point(40.7, 116.7, 3.0)],
+                },
+                ObjectTrack { id: "a".to_string(), points: vec![point(41.0, 117.0, 0.0), point(41.5, 117.5, 2.0)] },
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_columns_by_header_name() {
+        let csv = "time,longitude,vehicle_id,lat\n0,116,b,40\n0,117,a,41\n1,116.5,b,40.5\n";
+        let tracks = parse_fleet_csv(csv.as_bytes()).unwrap();
+        let headerless = parse_fleet_csv("b,40,116,0\na,41,117,0\nb,40.5,116.5,1\n".as_bytes()).unwrap();
+        assert_eq!(tracks, headerless);
+    }
+
+    #[test]
+    fn rejects_a_header_without_an_id_column() {
+        let error = parse_fleet_csv("lat,lon,time,alt\n40,116,0,150\n".as_bytes()).unwrap_err();
+        assert!(matches!(error, Error::MalformedLine { line: 1, ref message, .. } if message == "no id column"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_an_object_going_back_in_time() {
+        let error = parse_fleet_csv("a,40,116,5\nb,41,117,0\na,40,116,4\n".as_bytes()).unwrap_err();
+        assert!(matches!(error, Error::MalformedLine { line: 3, column: 4, .. }), "{}", error);
+    }
+}
@@ -0,0 +1,103 @@
+//! GeoJSON output: one `Feature` with a `LineString` geometry, or a
+//! `MultiLineString` for a track of several segments.
+//!
+//! Coordinates are in GeoJSON order, `[lon, lat]`, with the altitude as a
+//! third value when every point has one. The time of each vertex is in the
+//! `timestamps` property, in seconds since the epoch, nested like the
+//! coordinates.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::point::Point;
+
+/// Builds the GeoJSON `Feature` of a trajectory.
+pub fn to_geojson(points: &[Point]) -> Value {
+    let with_alt = !points.is_empty() && points.iter().all(|p| p.alt.is_some());
+    let (coordinates, timestamps) = line(points, with_alt);
+    feature("LineString", coordinates, timestamps)
+}
+
+/// Builds the GeoJSON `Feature` of a track of several segments, kept apart
+/// in a `MultiLineString`. A single segment, or none, is a `LineString`.
+pub fn segments_to_geojson(segments: &[Vec<Point>]) -> Value {
+    match segments {
+        [] => return to_geojson(&[]),
+        [points] => return to_geojson(points),
+        _ => {}
+    }
+    let with_alt = segments.iter().flatten().all(|p| p.alt.is_some()) && segments.iter().any(|s| !s.is_empty());
+    let (coordinates, timestamps): (Vec<Value>, Vec<Value>) =
+        segments.iter().map(|segment| line(segment, with_alt)).unzip();
+    feature("MultiLineString", coordinates.into(), timestamps.into())
+}
+
+// Coordinates and timestamps of a line
+fn line(points: &[Point], with_alt: bool) -> (Value, Value) {
+    let coordinates: Vec<Value> = points.iter().map(|p| match p.alt {
+        Some(alt) if with_alt => json!([p.lon, p.lat, alt]),
+        _ => json!([p.lon, p.lat]),
+    }).collect();
+    let timestamps: Vec<f64> = points.iter().map(|p| p.time).collect();
+    (coordinates.into(), timestamps.into())
+}
+
+fn feature(geometry: &str, coordinates: Value, timestamps: Value) -> Value {
+    json!({
+        "type": "Feature",
+        "geometry": {
+            "type": geometry,
+            "coordinates": coordinates,
+        },
+        "properties": {
+            "timestamps": timestamps,
+        },
+    })
+}
+
+/// Writes a trajectory to a GeoJSON file.
+pub fn write_geojson<P: AsRef<Path>>(points: &[Point], path: P) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_geojson_to(points, &mut writer)?;
+    writer.flush()
+}
+
+/// Writes a trajectory as a GeoJSON document.
+pub fn write_geojson_to<W: Write>(points: &[Point], writer: &mut W) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, &to_geojson(points))?;
+    writeln!(writer)
+}
+
+/// Writes a track of several segments as a GeoJSON document.
+pub fn write_geojson_segments_to<W: Write>(segments: &[Vec<Point>], writer: &mut W) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, &segments_to_geojson(segments))?;
+    writeln!(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lon: f64, time: f64) -> Point {
+        Point { lat, lon, time, alt: None }
+    }
+
+    #[test]
+    fn keeps_segments_apart() {
+        let segments = [vec![point(1.0, 2.0, 10.0), point(3.0, 4.0, 20.0)], vec![point(5.0, 6.0, 5.0)]];
+        let feature = segments_to_geojson(&segments);
+        assert_eq!(feature["geometry"]["type"], "MultiLineString");
+        assert_eq!(feature["geometry"]["coordinates"], json!([[[2.0, 1.0], [4.0, 3.0]], [[6.0, 5.0]]]));
+        assert_eq!(feature["properties"]["timestamps"], json!([[10.0, 20.0], [5.0]]));
+    }
+
+    #[test]
+    fn single_segment_is_a_line_string() {
+        let segments = [vec![point(1.0, 2.0, 10.0), point(3.0, 4.0, 20.0)]];
+        assert_eq!(segments_to_geojson(&segments), to_geojson(&segments[0]));
+        assert_eq!(segments_to_geojson(&[]), to_geojson(&[]));
+    }
+}
@@ -0,0 +1,276 @@
+//! GPX 1.1 tracks.
+//!
+//! Only `trk`/`trkseg`/`trkpt` are read; waypoints and routes are ignored.
+//! Every track point needs a `<time>`, since the compressors work on timed
+//! trajectories.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::error::{Error, Result};
+use crate::point::{check_trajectory, Point};
+use crate::time::{format_iso8601, parse_iso8601};
+
+/// A GPX `<trk>`, with its segments kept apart.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct GpxTrack {
+    pub name: Option<String>,
+    pub segments: Vec<Vec<Point>>,
+}
+
+impl GpxTrack {
+    /// Applies `compress` to every segment on its own, so segment
+    /// boundaries survive compression. Empty segments stay empty.
+    pub fn compress_segments<F>(&self, mut compress: F) -> Result<GpxTrack>
+    where F: FnMut(&[Point]) -> Result<Vec<Point>>, {
+        Ok(GpxTrack {
+            name: self.name.clone(),
+            segments: self
+                .segments
+                .iter()
+                .map(|segment| if segment.is_empty() { Ok(Vec::new()) } else { compress(segment) })
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+/// Reads the tracks of a GPX file.
+pub fn read_gpx<P: AsRef<Path>>(path: P) -> Result<Vec<GpxTrack>> {
+    parse_gpx(BufReader::new(File::open(path)?))
+}
+
+/// Parses the tracks of a GPX document.
+pub fn parse_gpx<R: BufRead>(mut reader: R) -> Result<Vec<GpxTrack>> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    let malformed = |position: u64, message: String| malformed(&text, position as usize, message);
+
+    let mut reader = Reader::from_str(&text);
+    reader.config_mut().trim_text(true);
+
+    let mut tracks = Vec::<GpxTrack>::new();
+    let mut point: Option<(Point, bool)> = None;
+    // Local name of the innermost open element whose text we want
+    let mut text_of: Option<Vec<u8>> = None;
+    // Elements are only recognised at their place under `<gpx>`, so that
+    // extensions reusing their names are skipped
+    let mut depth = 0;
+    let mut in_track = false;
+    let mut in_segment = false;
+    loop {
+        let position = reader.buffer_position();
+        let event = reader.read_event().map_err(|e| malformed(reader.error_position(), e.to_string()))?;
+        let no_track = || malformed(position, "track content outside a track".to_string());
+        match event {
+            Event::Start(e) => {
+                match (depth, e.local_name().as_ref()) {
+                    (1, b"trk") => {
+                        in_track = true;
+                        tracks.push(GpxTrack::default());
+                    }
+                    (2, b"trkseg") if in_track => {
+                        in_segment = true;
+                        tracks.last_mut().ok_or_else(no_track)?.segments.push(Vec::new());
+                    }
+                    (3, b"trkpt") if in_segment => {
+                        let p = track_point(&e).map_err(|message| malformed(position, message))?;
+                        point = Some((p, false));
+                    }
+                    (4, name @ (b"ele" | b"time")) if point.is_some() => text_of = Some(name.to_vec()),
+                    (2, b"name") if in_track => text_of = Some(b"name".to_vec()),
+                    _ => {}
+                }
+                depth += 1;
+            }
+            Event::Empty(e) if depth == 3 && e.local_name().as_ref() == b"trkpt" && in_segment => {
+                return Err(malformed(position, "track point without time".to_string()));
+            }
+            Event::Text(e) => {
+                let value = e.unescape().map_err(|e| malformed(position, e.to_string()))?;
+                match (text_of.as_deref(), point.as_mut()) {
+                    (Some(b"ele"), Some((p, _))) => {
+                        let ele = value.trim().parse().map_err(|_| malformed(position, format!("invalid elevation {:?}", value)))?;
+                        p.alt = Some(ele);
+                    }
+                    (Some(b"time"), Some((p, has_time))) => {
+                        p.time = parse_iso8601(&value).ok_or_else(|| malformed(position, format!("invalid time {:?}", value)))?;
+                        *has_time = true;
+                    }
+                    (Some(b"name"), None) => tracks.last_mut().ok_or_else(no_track)?.name = Some(value.into_owned()),
+                    _ => {}
+                }
+            }
+            Event::End(e) => {
+                depth -= 1;
+                match (depth, e.local_name().as_ref()) {
+                    (1, b"trk") => in_track = false,
+                    (2, b"trkseg") => in_segment = false,
+                    (3, b"trkpt") => {
+                        if let Some((p, has_time)) = point.take() {
+                            if !has_time {
+                                return Err(malformed(position, "track point without time".to_string()));
+                            }
+                            let track = tracks.last_mut().ok_or_else(no_track)?;
+                            track.segments.last_mut().ok_or_else(no_track)?.push(p);
+                        }
+                    }
+                    _ => text_of = None,
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let mut any_points = false;
+    for segment in tracks.iter().flat_map(|track| &track.segments) {
+        if !segment.is_empty() {
+            check_trajectory(segment)?;
+            any_points = true;
+        }
+    }
+    if !any_points {
+        return Err(Error::EmptyTrajectory);
+    }
+    Ok(tracks)
+}
+
+fn track_point(e: &BytesStart) -> std::result::Result<Point, String> {
+    let mut lat = None;
+    let mut lon = None;
+    for attr in e.attributes() {
+        let attr = attr.map_err(|e| e.to_string())?;
+        let value = attr.unescape_value().map_err(|e| e.to_string())?;
+        match attr.key.local_name().as_ref() {
+            b"lat" => lat = value.trim().parse::<f64>().ok(),
+            b"lon" => lon = value.trim().parse::<f64>().ok(),
+            _ => {}
+        }
+    }
+    match (lat, lon) {
+        (Some(lat), Some(lon)) => Ok(Point { lat, lon, ..Default::default() }),
+        _ => Err("track point without valid lat/lon".to_string()),
+    }
+}
+
+// Error at byte offset `position` of `text`, located by line and column
+fn malformed(text: &str, position: usize, message: String) -> Error {
+    let before = &text[..text.floor_char_boundary(position.min(text.len()))];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+    Error::MalformedLine { line, column, message }
+}
+
+/// Writes tracks to a GPX 1.1 file.
+pub fn write_gpx<P: AsRef<Path>>(tracks: &[GpxTrack], path: P) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_gpx_to(tracks, &mut writer)?;
+    writer.flush()
+}
+
+/// Writes tracks as a GPX 1.1 document.
+pub fn write_gpx_to<W: Write>(tracks: &[GpxTrack], writer: &mut W) -> io::Result<()> {
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(writer, r#"<gpx version="1.1" creator="trajcomp" xmlns="http://www.topografix.com/GPX/1/1">"#)?;
+    for track in tracks {
+        writeln!(writer, "  <trk>")?;
+        if let Some(name) = &track.name {
+            writeln!(writer, "    <name>{}</name>", escape(name.as_str()))?;
+        }
+        for segment in &track.segments {
+            writeln!(writer, "    <trkseg>")?;
+            for point in segment {
+                write!(writer, r#"      <trkpt lat="{}" lon="{}">"#, point.lat, point.lon)?;
+                if let Some(alt) = point.alt {
+                    write!(writer, "<ele>{}</ele>", alt)?;
+                }
+                writeln!(writer, "<time>{}</time></trkpt>", format_iso8601(point.time))?;
+            }
+            writeln!(writer, "    </trkseg>")?;
+        }
+        writeln!(writer, "  </trk>")?;
+    }
+    writeln!(writer, "</gpx>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lon: f64, time: f64, alt: Option<f64>) -> Point {
+        Point { lat, lon, time, alt }
+    }
+
+    #[test]
+    fn reads_tracks_and_segments() {
+        let gpx = r#"<?xml version="1.0"?>
+<gpx version="1.1" xmlns="http://www.topografix.com/GPX/1/1">
+  <wpt lat="1" lon="1"><time>2008-10-23T02:53:04Z</time></wpt>
+  <trk>
+    <name>A &amp; B</name>
+    <trkseg>
+      <trkpt lat="39.98" lon="116.31"><ele>150</ele><time>2008-10-23T02:53:04Z</time></trkpt>
+      <trkpt lat="39.99" lon="116.32"><time>2008-10-23T10:53:10.5+08:00</time></trkpt>
+    </trkseg>
+    <trkseg>
+      <trkpt lat="40" lon="116"><time>2008-10-23T03:00:00Z</time></trkpt>
+    </trkseg>
+  </trk>
+  <trk><trkseg></trkseg></trk>
+</gpx>"#;
+        let tracks = parse_gpx(gpx.as_bytes()).unwrap();
+        assert_eq!(tracks.len(), 2);
+        assert_eq!(tracks[0].name.as_deref(), Some("A & B"));
+        assert_eq!(
+            tracks[0].segments,
+            [
+                vec![point(39.98, 116.31, 1224730384.0, Some(150.0)), point(39.99, 116.32, 1224730390.5, None)],
+                vec![point(40.0, 116.0, 1224730800.0, None)],
+            ]
+        );
+        assert_eq!(tracks[1], GpxTrack { name: None, segments: vec![Vec::new()] });
+    }
+
+    #[test]
+    fn skips_extensions_reusing_gpx_names() {
+        let gpx = r#"<gpx xmlns="http://www.topografix.com/GPX/1/1" xmlns:x="urn:x">
+  <trk><trkseg>
+    <trkpt lat="1" lon="2"><time>2008-10-23T02:53:04Z</time>
+      <extensions><x:trk><x:trkseg><x:time>bogus</x:time></x:trkseg></x:trk><x:name>n</x:name></extensions>
+    </trkpt>
+  </trkseg></trk>
+</gpx>"#;
+        let tracks = parse_gpx(gpx.as_bytes()).unwrap();
+        assert_eq!(tracks, [GpxTrack { name: None, segments: vec![vec![point(1.0, 2.0, 1224730384.0, None)]] }]);
+    }
+
+    #[test]
+    fn rejects_points_without_time() {
+        let gpx = r#"<gpx><trk><trkseg><trkpt lat="1" lon="2"/></trkseg></trk></gpx>"#;
+        assert!(matches!(parse_gpx(gpx.as_bytes()), Err(Error::MalformedLine { line: 1, .. })));
+        let gpx = "<gpx><trk><trkseg>\n<trkpt lat=\"1\" lon=\"2\"><ele>3</ele></trkpt></trkseg></trk></gpx>";
+        assert!(matches!(parse_gpx(gpx.as_bytes()), Err(Error::MalformedLine { line: 2, .. })));
+    }
+
+    #[test]
+    fn write_then_parse_round_trips() {
+        let tracks = vec![
+            GpxTrack {
+                name: Some("<walk>".to_string()),
+                segments: vec![
+                    vec![point(39.984702, 116.318417, 1224730384.0, Some(492.0)), point(39.984683, 116.31845, 1224730389.25, None)],
+                    vec![point(-33.5, -70.25, 1224731000.0, Some(-1.5))],
+                ],
+            },
+            GpxTrack { name: None, segments: vec![vec![point(0.0, 0.0, 0.0, None)]] },
+        ];
+        let mut gpx = Vec::new();
+        write_gpx_to(&tracks, &mut gpx).unwrap();
+        assert_eq!(parse_gpx(gpx.as_slice()).unwrap(), tracks);
+    }
+}
@@ -0,0 +1,130 @@
+use std::fs::File;
+use std::io::{self, BufRead, Read, Write};
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::point::{check_trajectory, Point};
+
+pub mod fleet;
+pub mod geojson;
+pub mod gpx;
+pub mod plt;
+
+pub use fleet::{
+    compress_fleet, compress_fleet_with, is_fleet_header, parse_fleet_csv, read_fleet_csv, write_fleet_csv, write_fleet_csv_to,
+    ObjectTrack,
+};
+pub use geojson::{segments_to_geojson, to_geojson, write_geojson, write_geojson_segments_to, write_geojson_to};
+pub use gpx::{parse_gpx, read_gpx, write_gpx, write_gpx_to, GpxTrack};
+pub use plt::{parse_plt, read_plt};
+
+pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
+where P: AsRef<Path>, {
+    let file = File::open(filename)?;
+    Ok(io::BufReader::new(file).lines())
+}
+
+/// Reads a whitespace separated `lat lon unix_time` file, one point per line.
+/// Raw GeoLife `.plt` files are recognised by their header and parsed with
+/// [`read_plt`].
+pub fn gpsreader(filename: &str) -> Result<Vec<Point>> {
+    if is_geolife(filename) {
+        return read_plt(filename);
+    }
+    parse_txt(io::BufReader::new(File::open(filename)?))
+}
+
+/// Parses whitespace separated `lat lon unix_time` lines. Blank lines are
+/// skipped.
+pub fn parse_txt<R: BufRead>(reader: R) -> Result<Vec<Point>> {
+    // Define Point vector
+    let mut points: Vec<Point> = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let datapoint = line?;
+        if datapoint.trim().is_empty() {
+            continue;
+        }
+        let mut data = datapoint.split_whitespace();
+        points.push(Point {
+            lat: parse_field(data.next(), index + 1, 1)?,
+            lon: parse_field(data.next(), index + 1, 2)?,
+            time: parse_field(data.next(), index + 1, 3)?,
+            ..Default::default()
+        });
+    }
+    check_trajectory(&points)?;
+    Ok(points)
+}
+
+/// Parses headerless `lat,lon,time` CSV records, as written by
+/// [`write_to_file`].
+pub fn parse_csv<R: Read>(reader: R) -> Result<Vec<Point>> {
+    let mut reader = csv::ReaderBuilder::new().has_headers(false).flexible(true).from_reader(reader);
+    let mut points: Vec<Point> = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let line = record.position().map_or(0, |position| position.line() as usize);
+        points.push(Point {
+            lat: parse_field(record.get(0), line, 1)?,
+            lon: parse_field(record.get(1), line, 2)?,
+            time: parse_field(record.get(2), line, 3)?,
+            ..Default::default()
+        });
+    }
+    check_trajectory(&points)?;
+    Ok(points)
+}
+
+// Parses field number `column` of line `line` as a number
+pub(crate) fn parse_field(field: Option<&str>, line: usize, column: usize) -> Result<f64> {
+    let field = field.ok_or_else(|| Error::MalformedLine { line, column, message: "missing field".to_string() })?;
+    field.trim().parse().map_err(|_| Error::MalformedLine {
+        line,
+        column,
+        message: format!("invalid number {:?}", field),
+    })
+}
+
+fn is_geolife(filename: &str) -> bool {
+    match read_lines(filename) {
+        Ok(mut lines) => matches!(lines.next(), Some(Ok(line)) if line.starts_with(plt::PLT_SIGNATURE)),
+        Err(_) => false,
+    }
+}
+
+/// Writes points as headerless `lat,lon,time` CSV records, as a single GPX
+/// track when `path` ends in `.gpx`, or as a GeoJSON feature when it ends in
+/// `.geojson`.
+pub fn write_to_file(points: &[Point], path: &str) -> Result<()> {
+    let extension = Path::new(path).extension().and_then(|ext| ext.to_str()).unwrap_or("");
+    if extension.eq_ignore_ascii_case("gpx") {
+        let track = GpxTrack { name: None, segments: vec![points.to_vec()] };
+        write_gpx(&[track], path)?;
+        return Ok(());
+    }
+    if extension.eq_ignore_ascii_case("geojson") {
+        write_geojson(points, path)?;
+        return Ok(());
+    }
+
+    let mut file = File::create(path)?;
+    write_csv_to(points, &mut file)
+}
+
+/// Writes points as headerless `lat,lon,time` CSV records.
+pub fn write_csv_to<W: Write>(points: &[Point], writer: W) -> Result<()> {
+    // Creates new `Writer` on top of `writer`
+    let mut writer = csv::Writer::from_writer(writer);
+
+    // Write records
+    for point in points.iter() {
+        writer.write_record(&[point.lat.to_string(), point.lon.to_string(), point.time.to_string()])?;
+    }
+
+    // A CSV writer maintains an internal buffer, so it's important
+    // to flush the buffer when you're done.
+    writer.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! Reader for the raw GeoLife `.plt` format.
+//!
+//! A file starts with six header lines, followed by one record per line:
+//!
+//! ```text
+//! 39.984702,116.318417,0,492,39744.1201851852,2008-10-23,02:53:04
+//! ```
+//!
+//! with latitude, longitude, an unused field, altitude in feet (-777 when
+//! invalid), days since 1899-12-30, date and time in GMT.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::io::parse_field;
+use crate::point::{check_trajectory, Point};
+use crate::time::parse_date_time;
+
+/// First line of every GeoLife trajectory file.
+pub const PLT_SIGNATURE: &str = "Geolife trajectory";
+const HEADER_LINES: usize = 6;
+const FEET_TO_METRES: f64 = 0.3048;
+const INVALID_ALTITUDE: f64 = -777.0;
+
+/// Reads a GeoLife `.plt` file.
+pub fn read_plt<P: AsRef<Path>>(path: P) -> Result<Vec<Point>> {
+    parse_plt(BufReader::new(File::open(path)?))
+}
+
+/// Parses GeoLife `.plt` records, skipping the header.
+pub fn parse_plt<R: BufRead>(reader: R) -> Result<Vec<Point>> {
+    let mut points: Vec<Point> = Vec::new();
+    for (index, line) in reader.lines().enumerate().skip(HEADER_LINES) {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        points.push(parse_record(&line, index + 1)?);
+    }
+    check_trajectory(&points)?;
+    Ok(points)
+}
+
+fn parse_record(record: &str, line: usize) -> Result<Point> {
+    let fields: Vec<&str> = record.split(',').map(str::trim).collect();
+    let altitude = parse_field(fields.get(3).copied(), line, 4)?;
+    let date = fields.get(5).copied().unwrap_or("");
+    let time = fields.get(6).copied().unwrap_or("");
+    let time = parse_date_time(date, time).ok_or_else(|| Error::MalformedLine {
+        line,
+        column: 6,
+        message: format!("invalid date and time {:?} {:?}", date, time),
+    })?;
+    Ok(Point {
+        lat: parse_field(fields.first().copied(), line, 1)?,
+        lon: parse_field(fields.get(1).copied(), line, 2)?,
+        time,
+        alt: if altitude == INVALID_ALTITUDE { None } else { Some(altitude * FEET_TO_METRES) },
+    })
+}
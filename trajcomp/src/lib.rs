@@ -0,0 +1,22 @@
+//! Trajectory compression algorithms and the shared types they operate on.
+//!
+//! Every compressor takes the original GPS points in time order and returns
+//! the subset of points it keeps, always including the first and last one.
+
+pub mod algorithm;
+pub mod distance;
+pub mod error;
+pub mod evaluation;
+pub mod io;
+pub mod metric;
+pub mod offline;
+pub mod online;
+pub mod parallel;
+pub mod point;
+pub mod reconstruction;
+mod time;
+pub mod tuning;
+
+pub use algorithm::Algorithm;
+pub use error::{Error, Result};
+pub use point::{check_trajectory, Point};
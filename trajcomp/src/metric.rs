@@ -0,0 +1,214 @@
+//! Errors of a point against the segment that stands in for it once the
+//! points between the segment's ends are dropped.
+//!
+//! An [`ErrorMetric`] measures the middle point `m` of an original stretch
+//! `s .. m .. e` against the segment `s -> e`: [`Ped`] and [`Sed`] by
+//! position, [`Dad`] by direction and [`Speed`] by speed. Metrics that also
+//! look at the neighbours of `m`, as DAD does, measure it within the whole
+//! stretch with [`ErrorMetric::span_error`]. The window,
+//! top-down and bottom-up compressors take any metric, as does
+//! [`evaluate_metric`](crate::evaluation::evaluate_metric); [`Metric`] picks
+//! one at run time.
+
+use std::f64::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::distance::DistanceModel;
+use crate::point::Point;
+
+/// Error of `m` when the segment `s -> e` replaces it.
+pub trait ErrorMetric {
+    fn error(&self, s: &Point, m: &Point, e: &Point) -> f64;
+
+    /// Error of `span[i]` when the segment `s -> e` replaces the consecutive
+    /// original points `span`. By default that of the point alone.
+    fn span_error(&self, s: &Point, e: &Point, span: &[Point], i: usize) -> f64 {
+        self.error(s, &span[i], e)
+    }
+}
+
+impl<F: Fn(&Point, &Point, &Point) -> f64> ErrorMetric for F {
+    fn error(&self, s: &Point, m: &Point, e: &Point) -> f64 {
+        self(s, m, e)
+    }
+}
+
+impl ErrorMetric for Box<dyn ErrorMetric> {
+    fn error(&self, s: &Point, m: &Point, e: &Point) -> f64 {
+        (**self).error(s, m, e)
+    }
+
+    fn span_error(&self, s: &Point, e: &Point, span: &[Point], i: usize) -> f64 {
+        (**self).span_error(s, e, span, i)
+    }
+}
+
+/// Perpendicular Euclidean distance from the line through `s` and `e`.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct Ped(pub DistanceModel);
+
+impl ErrorMetric for Ped {
+    fn error(&self, s: &Point, m: &Point, e: &Point) -> f64 {
+        self.0.ped(s, m, e)
+    }
+}
+
+/// Synchronized Euclidean distance from the position on `s -> e` at the
+/// time of `m`.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct Sed(pub DistanceModel);
+
+impl ErrorMetric for Sed {
+    fn error(&self, s: &Point, m: &Point, e: &Point) -> f64 {
+        self.0.sed(s, m, e)
+    }
+}
+
+/// Largest difference between the speed along `s -> e` and the speeds
+/// along `s -> m` and `m -> e`, in distance units per second. Moving
+/// without time passing is an infinite speed.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct Speed(pub DistanceModel);
+
+impl Speed {
+    fn speed(&self, a: &Point, b: &Point) -> f64 {
+        let distance = self.0.distance(a, b);
+        if distance == 0.0 {
+            0.0
+        } else {
+            distance / (b.time - a.time)
+        }
+    }
+}
+
+impl ErrorMetric for Speed {
+    fn error(&self, s: &Point, m: &Point, e: &Point) -> f64 {
+        let segment = self.speed(s, e);
+        let deviation = |speed: f64| if speed == segment { 0.0 } else { (speed - segment).abs() };
+        deviation(self.speed(s, m)).max(deviation(self.speed(m, e)))
+    }
+}
+
+/// Direction-aware distance (DAD): the largest angle between the heading of
+/// `s -> e` and the headings of the original segments, in radians from 0 to
+/// π. A point is measured by the two segments it ends, and with no other
+/// points known, those are `s -> m` and `m -> e`. Staying in place has no
+/// heading and no error, but moving away from and back to the same spot is
+/// the largest error, π.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct Dad(pub DistanceModel);
+
+impl Dad {
+    fn heading(&self, a: &Point, b: &Point) -> Option<f64> {
+        if a.lat == b.lat && a.lon == b.lon {
+            None
+        } else {
+            Some(self.0.heading(a, b))
+        }
+    }
+}
+
+impl ErrorMetric for Dad {
+    fn error(&self, s: &Point, m: &Point, e: &Point) -> f64 {
+        self.span_error(s, e, &[*s, *m, *e], 1)
+    }
+
+    fn span_error(&self, s: &Point, e: &Point, span: &[Point], i: usize) -> f64 {
+        let segment = self.heading(s, e);
+        let deviation = |a: &Point, b: &Point| match (self.heading(a, b), segment) {
+            (None, _) => 0.0,
+            (Some(_), None) => PI,
+            (Some(heading), Some(segment)) => {
+                let angle = (heading - segment).rem_euclid(2.0 * PI);
+                angle.min(2.0 * PI - angle)
+            }
+        };
+        let before = i.checked_sub(1).map_or(0.0, |prev| deviation(&span[prev], &span[i]));
+        let after = span.get(i + 1).map_or(0.0, |next| deviation(&span[i], next));
+        before.max(after)
+    }
+}
+
+/// One of the metrics above, chosen at run time.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Metric {
+    #[default]
+    Ped,
+    Sed,
+    Dad,
+    Speed,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 4] = [Metric::Ped, Metric::Sed, Metric::Dad, Metric::Speed];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Metric::Ped => "ped",
+            Metric::Sed => "sed",
+            Metric::Dad => "dad",
+            Metric::Speed => "speed",
+        }
+    }
+
+    /// The metric, measuring distances and headings with `model`.
+    pub fn with_model(&self, model: DistanceModel) -> Box<dyn ErrorMetric> {
+        match self {
+            Metric::Ped => Box::new(Ped(model)),
+            Metric::Sed => Box::new(Sed(model)),
+            Metric::Dad => Box::new(Dad(model)),
+            Metric::Speed => Box::new(Speed(model)),
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Metric::ALL
+            .into_iter()
+            .find(|metric| metric.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown metric {:?}, expected one of ped, sed, dad, speed", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lon: f64) -> Point {
+        Point { lat, lon, ..Default::default() }
+    }
+
+    #[test]
+    fn dad_measures_the_original_segments() {
+        let span = [point(0.0, 0.0), point(1.0, 0.0), point(1.0, 0.01), point(2.0, 0.01)];
+        let (s, e) = (&span[0], &span[3]);
+        let dad = Dad::default();
+        // 1 -> 2 is at right angles to s -> e, unlike the chords s -> 1 -> e
+        assert!(dad.error(s, &span[1], e) < 0.01);
+        for i in [1, 2] {
+            assert!((dad.span_error(s, e, &span, i) - PI / 2.0).abs() < 0.01);
+        }
+        assert_eq!(Metric::Dad.with_model(DistanceModel::Planar).span_error(s, e, &span, 2), dad.span_error(s, e, &span, 2));
+    }
+
+    #[test]
+    fn other_metrics_measure_the_point_alone() {
+        let span = [point(0.0, 0.0), point(1.0, 1.0), point(1.0, 2.0), point(0.0, 3.0)];
+        for metric in [Metric::Ped, Metric::Sed, Metric::Speed] {
+            let metric = metric.with_model(DistanceModel::Planar);
+            for i in [1, 2] {
+                assert_eq!(metric.span_error(&span[0], &span[3], &span, i), metric.error(&span[0], &span[i], &span[3]));
+            }
+        }
+    }
+}
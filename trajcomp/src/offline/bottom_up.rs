@@ -0,0 +1,35 @@
+use crate::error::Result;
+use crate::metric::ErrorMetric;
+use crate::online::priority_list::PriorityList;
+use crate::point::{check_trajectory, Point};
+
+/// Bottom-up simplification bounded by `epsilon` under `metric`: starting
+/// from every point, the two adjacent segments that merge at the lowest
+/// cost are merged, for as long as the cost stays within `epsilon`. The
+/// cost of a merge is the largest error of an original point against the
+/// merged segment.
+pub fn bottom_up<M: ErrorMetric>(points: &[Point], metric: M, epsilon: f64) -> Result<Vec<Point>> {
+    check_trajectory(points)?;
+    // All points go in before any leaves, so a point's slot is its index
+    let mut buffer = PriorityList::with_capacity(points.len());
+    for point in points {
+        buffer.push_back(*point);
+    }
+    let cost = |s: usize, e: usize| {
+        let span = &points[s..=e];
+        (1..span.len() - 1).map(|i| metric.span_error(&points[s], &points[e], span, i)).fold(0.0, f64::max)
+    };
+    for i in 1..points.len().saturating_sub(1) {
+        buffer.set_priority(i, cost(i - 1, i + 1));
+    }
+
+    while buffer.min_priority().is_some_and(|min_cost| min_cost <= epsilon) {
+        let Some((prev, next, _)) = buffer.remove_min() else { break };
+        for slot in [prev, next] {
+            if let (Some(s), Some(e)) = (buffer.prev(slot), buffer.next(slot)) {
+                buffer.set_priority(slot, cost(s, e));
+            }
+        }
+    }
+    Ok(buffer.points().collect())
+}
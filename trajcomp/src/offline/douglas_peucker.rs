@@ -0,0 +1,16 @@
+use crate::distance::DistanceModel;
+use crate::metric::Ped;
+use crate::offline::top_down;
+use crate::error::Result;
+use crate::point::Point;
+
+/// Douglas-Peucker (top-down) simplification bounded by the perpendicular
+/// distance `epsilon`.
+pub fn douglas_peucker(points: &[Point], epsilon: f64) -> Result<Vec<Point>> {
+    douglas_peucker_with_model(points, epsilon, DistanceModel::Planar)
+}
+
+/// [`douglas_peucker`] with the perpendicular distance measured by `model`.
+pub fn douglas_peucker_with_model(points: &[Point], epsilon: f64, model: DistanceModel) -> Result<Vec<Point>> {
+    top_down(points, Ped(model), epsilon)
+}
@@ -0,0 +1,51 @@
+//! Offline compressors, which need the whole trajectory up front. They serve
+//! as a baseline for the online algorithms.
+
+use crate::error::Result;
+use crate::metric::ErrorMetric;
+use crate::point::{check_trajectory, Point};
+
+pub mod bottom_up;
+pub mod douglas_peucker;
+pub mod td_tr;
+pub mod visvalingam;
+
+pub use bottom_up::bottom_up;
+pub use douglas_peucker::{douglas_peucker, douglas_peucker_with_model};
+pub use td_tr::{td_tr, td_tr_with_model};
+pub use visvalingam::{visvalingam, visvalingam_with_model, VwLimit};
+
+/// Top-down simplification bounded by `epsilon` under `metric`, which is
+/// Douglas-Peucker under PED and TD-TR under SED: the point with the largest
+/// error against the segment between two kept points is kept as long as that
+/// error exceeds `epsilon`, splitting the segment in two.
+pub fn top_down<M: ErrorMetric>(points: &[Point], metric: M, epsilon: f64) -> Result<Vec<Point>> {
+    check_trajectory(points)?;
+    if points.len() < 3 {
+        return Ok(points.to_vec());
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // Segments still to be split, as (start, end) indices
+    let mut segments = vec![(0, points.len() - 1)];
+    while let Some((s, e)) = segments.pop() {
+        let mut max_d = 0.0;
+        let mut max_index = s;
+        for i in s + 1..e {
+            let d = metric.span_error(&points[s], &points[e], &points[s..=e], i - s);
+            if d > max_d {
+                max_d = d;
+                max_index = i;
+            }
+        }
+        if max_d > epsilon {
+            keep[max_index] = true;
+            segments.push((max_index, e));
+            segments.push((s, max_index));
+        }
+    }
+
+    Ok(points.iter().zip(keep).filter(|(_, k)| *k).map(|(p, _)| *p).collect())
+}
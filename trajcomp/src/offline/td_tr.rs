@@ -0,0 +1,16 @@
+use crate::distance::DistanceModel;
+use crate::metric::Sed;
+use crate::offline::top_down;
+use crate::error::Result;
+use crate::point::Point;
+
+/// Top-Down Time-Ratio (TD-TR): Douglas-Peucker bounded by the synchronized
+/// Euclidean distance `epsilon`, the offline counterpart of OPW-TR.
+pub fn td_tr(points: &[Point], epsilon: f64) -> Result<Vec<Point>> {
+    td_tr_with_model(points, epsilon, DistanceModel::Planar)
+}
+
+/// [`td_tr`] with the synchronized Euclidean distance measured by `model`.
+pub fn td_tr_with_model(points: &[Point], epsilon: f64, model: DistanceModel) -> Result<Vec<Point>> {
+    top_down(points, Sed(model), epsilon)
+}
@@ -0,0 +1,67 @@
+//! Visvalingam-Whyatt simplification: repeatedly drops the point whose
+//! triangle with its two neighbours, its effective area, is the smallest.
+//!
+//! A neighbour's effective area never drops below that of a point removed
+//! next to it, so points go in order of effective area and a point budget
+//! and an area threshold remove the same points in the same order.
+
+use crate::distance::DistanceModel;
+use crate::error::Result;
+use crate::online::priority_list::PriorityList;
+use crate::point::{check_trajectory, Point};
+
+/// When Visvalingam-Whyatt stops removing points.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum VwLimit {
+    /// Keep this many points, and at least the first and last one.
+    Points(usize),
+    /// Remove every point with an effective area below this one.
+    Area(f64),
+}
+
+/// Simplifies `points` down to `limit`, with areas in square degrees.
+pub fn visvalingam(points: &[Point], limit: VwLimit) -> Result<Vec<Point>> {
+    visvalingam_with_model(points, limit, DistanceModel::Planar)
+}
+
+/// Simplifies `points` down to `limit`, measuring areas with `model`.
+pub fn visvalingam_with_model(points: &[Point], limit: VwLimit, model: DistanceModel) -> Result<Vec<Point>> {
+    check_trajectory(points)?;
+    let mut buffer = PriorityList::with_capacity(points.len());
+    for point in points {
+        let last = buffer.push_back(*point);
+        if let Some(curr) = buffer.prev(last) {
+            set_area(&mut buffer, curr, 0.0, model);
+        }
+    }
+    while !limit_reached(&mut buffer, limit) && remove_smallest(&mut buffer, model) {}
+    Ok(buffer.points().collect())
+}
+
+// Whether the buffer is down to `limit`
+pub(crate) fn limit_reached(buffer: &mut PriorityList, limit: VwLimit) -> bool {
+    match limit {
+        VwLimit::Points(max_points) => buffer.len() <= max_points.max(2),
+        VwLimit::Area(min_area) => buffer.min_priority().is_none_or(|area| area >= min_area),
+    }
+}
+
+// Sets the effective area of the point in `slot` to its triangle's area,
+// but no less than `floor`. An endpoint has no triangle and keeps the largest
+// floor it is given, for when it becomes an inner point.
+pub(crate) fn set_area(buffer: &mut PriorityList, slot: usize, floor: f64, model: DistanceModel) {
+    let area = match (buffer.prev(slot), buffer.next(slot)) {
+        (Some(prev), Some(next)) => model.area(buffer.point(prev), buffer.point(slot), buffer.point(next)),
+        _ => buffer.priority(slot),
+    };
+    buffer.set_priority(slot, area.max(floor));
+}
+
+// Removes the inner point with the smallest effective area and updates its
+// neighbours. False when there is no inner point.
+pub(crate) fn remove_smallest(buffer: &mut PriorityList, model: DistanceModel) -> bool {
+    let Some((prev, next, area)) = buffer.remove_min() else { return false };
+    set_area(buffer, prev, area, model);
+    set_area(buffer, next, area, model);
+    true
+}
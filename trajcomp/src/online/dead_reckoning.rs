@@ -0,0 +1,171 @@
+use crate::distance::DistanceModel;
+use crate::online::OnlineCompressor;
+use crate::error::Result;
+use crate::point::Point;
+
+/// Dead reckoning compression: a point is kept once the accumulated
+/// deviation from the heading of the last kept segment exceeds `eps`.
+///
+/// Time plays no part; see [`VelocityDeadReckoning`] for prediction from
+/// speed and heading.
+#[derive(Debug, Clone)]
+pub struct DeadReckoning {
+    eps: f64,
+    model: DistanceModel,
+    max_d: f64,
+    // Heading of the segment leaving the last kept point
+    start_angle: Option<f64>,
+    prev: Option<Point>,
+    prev_kept: bool,
+}
+
+impl DeadReckoning {
+    pub fn new(eps: f64) -> Self {
+        DeadReckoning { eps, model: DistanceModel::Planar, max_d: 0.0, start_angle: None, prev: None, prev_kept: false }
+    }
+
+    /// Measures distances with `model` instead of planar degrees.
+    pub fn with_model(mut self, model: DistanceModel) -> Self {
+        self.model = model;
+        self
+    }
+}
+
+impl OnlineCompressor for DeadReckoning {
+    fn push(&mut self, point: Point, out: &mut Vec<Point>) {
+        let prev = match self.prev {
+            Some(prev) => prev,
+            None => {
+                out.push(point);
+                self.prev = Some(point);
+                self.prev_kept = true;
+                return;
+            }
+        };
+        let d = self.model.distance(&prev, &point);
+        let angle = self.model.heading(&prev, &point);
+        match self.start_angle {
+            None => self.start_angle = Some(angle),
+            Some(start_angle) => {
+                self.max_d += (d * (angle - start_angle).sin()).abs();
+                if self.max_d.abs() > self.eps {
+                    self.max_d = 0.0;
+                    out.push(prev);
+                    self.start_angle = Some(angle);
+                }
+            }
+        }
+        self.prev = Some(point);
+        self.prev_kept = false;
+    }
+
+    fn finish(&mut self, out: &mut Vec<Point>) {
+        if let Some(prev) = self.prev {
+            if !self.prev_kept {
+                out.push(prev);
+            }
+        }
+        *self = DeadReckoning::new(self.eps).with_model(self.model);
+    }
+}
+
+/// Compresses a whole trajectory with [`DeadReckoning`].
+pub fn dead_reckoning(points: &[Point], eps: f64) -> Result<Vec<Point>> {
+    DeadReckoning::new(eps).compress(points)
+}
+
+/// Dead reckoning as a vessel or vehicle reports its position: the velocity
+/// at the last kept point, taken from the segment arriving there, predicts
+/// where the object is at each later timestamp, and the point is kept when
+/// it lies more than `threshold` metres from the prediction.
+///
+/// The first point has no arriving segment and takes the velocity of the
+/// segment leaving it. Positions are extrapolated linearly in latitude and
+/// longitude. Points that come with no time elapsed keep the previous
+/// velocity.
+#[derive(Debug, Clone)]
+pub struct VelocityDeadReckoning {
+    threshold: f64,
+    model: DistanceModel,
+    // Last kept point and its velocity in degrees of (lat, lon) per second
+    anchor: Option<Point>,
+    velocity: Option<(f64, f64)>,
+    prev: Option<Point>,
+    prev_kept: bool,
+}
+
+impl VelocityDeadReckoning {
+    pub fn new(threshold: f64) -> Self {
+        VelocityDeadReckoning {
+            threshold,
+            model: DistanceModel::Haversine,
+            anchor: None,
+            velocity: None,
+            prev: None,
+            prev_kept: false,
+        }
+    }
+
+    /// Measures the distance from the prediction with `model` instead of
+    /// the great circle. The threshold stays in metres, so
+    /// [`DistanceModel::Planar`] measures on the great circle too.
+    pub fn with_model(mut self, model: DistanceModel) -> Self {
+        self.model = if model == DistanceModel::Planar { DistanceModel::Haversine } else { model };
+        self
+    }
+}
+
+// Velocity along `a -> b`, if any time passes
+fn velocity(a: &Point, b: &Point) -> Option<(f64, f64)> {
+    let dt = b.time - a.time;
+    (dt > 0.0).then(|| ((b.lat - a.lat) / dt, (b.lon - a.lon) / dt))
+}
+
+impl OnlineCompressor for VelocityDeadReckoning {
+    fn push(&mut self, point: Point, out: &mut Vec<Point>) {
+        let (Some(anchor), Some(prev)) = (self.anchor, self.prev) else {
+            out.push(point);
+            self.anchor = Some(point);
+            self.prev = Some(point);
+            self.prev_kept = true;
+            return;
+        };
+        match self.velocity {
+            None => self.velocity = velocity(&anchor, &point),
+            Some((lat_speed, lon_speed)) => {
+                let dt = point.time - anchor.time;
+                let predicted = Point {
+                    lat: anchor.lat + lat_speed * dt,
+                    lon: anchor.lon + lon_speed * dt,
+                    time: point.time,
+                    alt: None,
+                };
+                if self.model.distance(&predicted, &point) > self.threshold {
+                    out.push(point);
+                    self.anchor = Some(point);
+                    self.velocity = velocity(&prev, &point).or(self.velocity);
+                    self.prev = Some(point);
+                    self.prev_kept = true;
+                    return;
+                }
+            }
+        }
+        self.prev = Some(point);
+        self.prev_kept = false;
+    }
+
+    fn finish(&mut self, out: &mut Vec<Point>) {
+        if let Some(prev) = self.prev {
+            if !self.prev_kept {
+                out.push(prev);
+            }
+        }
+        *self = VelocityDeadReckoning::new(self.threshold).with_model(self.model);
+    }
+}
+
+/// Compresses a whole trajectory with [`VelocityDeadReckoning`], the
+/// threshold in metres.
+pub fn velocity_dead_reckoning(points: &[Point], threshold: f64) -> Result<Vec<Point>> {
+    VelocityDeadReckoning::new(threshold).compress(points)
+}
@@ -0,0 +1,55 @@
+//! Online compressors, which decide on each point after seeing a bounded
+//! window of the trajectory.
+
+use crate::error::Result;
+use crate::point::{check_trajectory, Point};
+
+pub mod dead_reckoning;
+pub mod opw;
+pub mod opw_tr;
+pub(crate) mod priority_list;
+pub mod sliding_window;
+pub mod squish;
+pub mod squish_e;
+pub mod sttrace;
+pub mod threshold;
+pub mod uniform;
+pub mod visvalingam;
+
+pub use dead_reckoning::{dead_reckoning, velocity_dead_reckoning, DeadReckoning, VelocityDeadReckoning};
+pub use opw::{opw, Opw};
+pub use opw_tr::{opw_tr, OpwTr};
+pub use sliding_window::{sliding_window, SlidingWindow};
+pub use squish::{squish, Squish};
+pub use squish_e::{squish_e, SquishE};
+pub use sttrace::{sttrace, STTrace};
+pub use threshold::{threshold, Threshold};
+pub use uniform::{uniform, Uniform};
+pub use visvalingam::{buffered_visvalingam, BufferedVisvalingam};
+
+/// A compressor that is fed one point at a time.
+///
+/// Points are emitted into `out` as soon as the compressor knows they are
+/// part of the result, so a live feed can be compressed without keeping the
+/// whole trajectory in memory.
+pub trait OnlineCompressor {
+    /// Feeds the next point of the trajectory and appends any points that
+    /// became final.
+    fn push(&mut self, point: Point, out: &mut Vec<Point>);
+
+    /// Ends the trajectory and appends the points still held back. The
+    /// compressor is reset and can be reused for another trajectory.
+    fn finish(&mut self, out: &mut Vec<Point>);
+
+    /// Compresses a whole trajectory, after checking that it is non-empty
+    /// and ordered in time.
+    fn compress(&mut self, points: &[Point]) -> Result<Vec<Point>> {
+        check_trajectory(points)?;
+        let mut out = Vec::new();
+        for point in points {
+            self.push(*point, &mut out);
+        }
+        self.finish(&mut out);
+        Ok(out)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::distance::DistanceModel;
+use crate::error::Result;
+use crate::metric::Ped;
+use crate::online::{OnlineCompressor, SlidingWindow};
+use crate::point::Point;
+
+/// Opening window compression bounded by the perpendicular distance `eps`:
+/// a [`SlidingWindow`] under [`Ped`].
+pub type Opw = SlidingWindow<Ped>;
+
+impl Opw {
+    pub fn new(eps: f64) -> Self {
+        SlidingWindow::with_metric(Ped::default(), eps)
+    }
+
+    /// Measures distances with `model` instead of planar degrees.
+    pub fn with_model(mut self, model: DistanceModel) -> Self {
+        self.metric = Ped(model);
+        self
+    }
+}
+
+/// Compresses a whole trajectory with [`Opw`].
+pub fn opw(points: &[Point], eps: f64) -> Result<Vec<Point>> {
+    Opw::new(eps).compress(points)
+}
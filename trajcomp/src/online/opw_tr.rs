@@ -0,0 +1,26 @@
+use crate::distance::DistanceModel;
+use crate::error::Result;
+use crate::metric::Sed;
+use crate::online::{OnlineCompressor, SlidingWindow};
+use crate::point::Point;
+
+/// Opening window compression bounded by the synchronized Euclidean
+/// distance `epsilon` (OPW-TR): a [`SlidingWindow`] under [`Sed`].
+pub type OpwTr = SlidingWindow<Sed>;
+
+impl OpwTr {
+    pub fn new(epsilon: f64) -> Self {
+        SlidingWindow::with_metric(Sed::default(), epsilon)
+    }
+
+    /// Measures distances with `model` instead of planar degrees.
+    pub fn with_model(mut self, model: DistanceModel) -> Self {
+        self.metric = Sed(model);
+        self
+    }
+}
+
+/// Compresses a whole trajectory with [`OpwTr`].
+pub fn opw_tr(points: &[Point], epsilon: f64) -> Result<Vec<Point>> {
+    OpwTr::new(epsilon).compress(points)
+}
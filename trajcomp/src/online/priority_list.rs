@@ -0,0 +1,307 @@
+//! The buffer shared by SQUISH, SQUISH-E, STTrace, Visvalingam-Whyatt and
+//! bottom-up: a doubly linked list of points ordered by time, with a priority
+//! queue over the inner points so the one with the lowest priority can be
+//! evicted in logarithmic time. The priority is an SED for the SQUISH family,
+//! an effective area for Visvalingam-Whyatt and a merge cost for bottom-up.
+//!
+//! The queue uses lazy deletion: updating a priority pushes a new entry and
+//! leaves the old one behind, to be skipped when it reaches the top. Ties go
+//! to the oldest point, as with a left-to-right linear scan.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::point::Point;
+
+/// Index of a point in the buffer, valid until the point is removed.
+pub(crate) type Slot = usize;
+
+const NIL: Slot = usize::MAX;
+
+#[derive(Debug, Clone)]
+struct Node {
+    point: Point,
+    priority: f64,
+    prev: Slot,
+    next: Slot,
+    // Arrival order, for tie-breaking
+    seq: u64,
+    // Bumped on every priority change and removal, invalidating queue entries
+    stamp: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    priority: f64,
+    seq: u64,
+    slot: Slot,
+    stamp: u64,
+}
+
+// Reversed, so that `BinaryHeap` pops the lowest priority first
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.total_cmp(&self.priority).then(other.seq.cmp(&self.seq))
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PriorityList {
+    nodes: Vec<Node>,
+    free: Vec<Slot>,
+    head: Option<Slot>,
+    tail: Option<Slot>,
+    len: usize,
+    queue: BinaryHeap<Entry>,
+    seq: u64,
+}
+
+impl PriorityList {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        PriorityList { nodes: Vec::with_capacity(capacity), ..Default::default() }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub(crate) fn point(&self, slot: Slot) -> &Point {
+        &self.nodes[slot].point
+    }
+
+    pub(crate) fn priority(&self, slot: Slot) -> f64 {
+        self.nodes[slot].priority
+    }
+
+    pub(crate) fn prev(&self, slot: Slot) -> Option<Slot> {
+        Some(self.nodes[slot].prev).filter(|&s| s != NIL)
+    }
+
+    pub(crate) fn next(&self, slot: Slot) -> Option<Slot> {
+        Some(self.nodes[slot].next).filter(|&s| s != NIL)
+    }
+
+    /// Appends `point` with a priority of zero and returns its slot.
+    pub(crate) fn push_back(&mut self, point: Point) -> Slot {
+        let node = Node { point, priority: 0.0, prev: self.tail.unwrap_or(NIL), next: NIL, seq: self.seq, stamp: 0 };
+        self.seq += 1;
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                let stamp = self.nodes[slot].stamp;
+                self.nodes[slot] = Node { stamp, ..node };
+                slot
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        match self.tail {
+            Some(tail) => {
+                self.nodes[tail].next = slot;
+                // The old tail now has neighbours on both sides
+                if self.nodes[tail].prev != NIL {
+                    self.enqueue(tail);
+                }
+            }
+            None => self.head = Some(slot),
+        }
+        self.tail = Some(slot);
+        self.len += 1;
+        slot
+    }
+
+    /// Removes the first point. The next one becomes the first and leaves
+    /// the queue.
+    pub(crate) fn pop_front(&mut self) {
+        if let Some(head) = self.head {
+            self.unlink(head);
+        }
+        if let Some(head) = self.head {
+            self.nodes[head].stamp += 1;
+        }
+    }
+
+    /// Removes the last point. The previous one becomes the last and leaves
+    /// the queue.
+    pub(crate) fn pop_back(&mut self) {
+        if let Some(tail) = self.tail {
+            self.unlink(tail);
+        }
+        if let Some(tail) = self.tail {
+            self.nodes[tail].stamp += 1;
+        }
+    }
+
+    /// Sets the priority of the point in `slot`.
+    pub(crate) fn set_priority(&mut self, slot: Slot, priority: f64) {
+        self.nodes[slot].priority = priority;
+        if self.prev(slot).is_some() && self.next(slot).is_some() {
+            self.enqueue(slot);
+        }
+    }
+
+    /// The lowest priority among the inner points.
+    pub(crate) fn min_priority(&mut self) -> Option<f64> {
+        while let Some(entry) = self.queue.peek() {
+            if self.nodes[entry.slot].stamp == entry.stamp {
+                return Some(entry.priority);
+            }
+            self.queue.pop();
+        }
+        None
+    }
+
+    /// Removes the inner point with the lowest priority and returns the slots
+    /// of its former neighbours along with its priority.
+    pub(crate) fn remove_min(&mut self) -> Option<(Slot, Slot, f64)> {
+        while let Some(entry) = self.queue.pop() {
+            if self.nodes[entry.slot].stamp != entry.stamp {
+                continue;
+            }
+            let node = &self.nodes[entry.slot];
+            let removed = (node.prev, node.next, node.priority);
+            self.unlink(entry.slot);
+            return Some(removed);
+        }
+        None
+    }
+
+    /// The buffered points, oldest first.
+    pub(crate) fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        let mut slot = self.head.unwrap_or(NIL);
+        std::iter::from_fn(move || {
+            let node = self.nodes.get(slot)?;
+            slot = node.next;
+            Some(node.point)
+        })
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.nodes.clear();
+        self.free.clear();
+        self.queue.clear();
+        self.head = None;
+        self.tail = None;
+        self.len = 0;
+    }
+
+    fn enqueue(&mut self, slot: Slot) {
+        let node = &mut self.nodes[slot];
+        node.stamp += 1;
+        self.queue.push(Entry { priority: node.priority, seq: node.seq, slot, stamp: node.stamp });
+        // Drop stale entries once they outnumber the live ones
+        if self.queue.len() > 2 * self.len + 16 {
+            let nodes = &self.nodes;
+            self.queue.retain(|entry| nodes[entry.slot].stamp == entry.stamp);
+        }
+    }
+
+    fn unlink(&mut self, slot: Slot) {
+        let Node { prev, next, .. } = self.nodes[slot];
+        match prev {
+            NIL => self.head = Some(next).filter(|&s| s != NIL),
+            prev => self.nodes[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = Some(prev).filter(|&s| s != NIL),
+            next => self.nodes[next].prev = prev,
+        }
+        self.nodes[slot].stamp += 1;
+        self.free.push(slot);
+        self.len -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(count: usize) -> (PriorityList, Vec<Slot>) {
+        let mut list = PriorityList::with_capacity(count);
+        let slots = (0..count).map(|i| list.push_back(Point { lat: i as f64, lon: 0.0, time: i as f64, alt: None })).collect();
+        (list, slots)
+    }
+
+    fn lats(list: &PriorityList) -> Vec<f64> {
+        list.points().map(|p| p.lat).collect()
+    }
+
+    #[test]
+    fn removes_the_lowest_priority_after_updates() {
+        let (mut list, slots) = list(5);
+        list.set_priority(slots[1], 1.0);
+        list.set_priority(slots[2], 2.0);
+        list.set_priority(slots[3], 3.0);
+        // The stale entry of priority 1 must not win
+        list.set_priority(slots[1], 4.0);
+        assert_eq!(list.min_priority(), Some(2.0));
+        assert_eq!(list.remove_min(), Some((slots[1], slots[3], 2.0)));
+        assert_eq!(list.remove_min(), Some((slots[1], slots[4], 3.0)));
+        assert_eq!(list.remove_min(), Some((slots[0], slots[4], 4.0)));
+        assert_eq!(list.remove_min(), None);
+        assert_eq!(lats(&list), [0.0, 4.0]);
+    }
+
+    #[test]
+    fn ties_go_to_the_oldest_point() {
+        let (mut list, slots) = list(4);
+        list.set_priority(slots[2], 1.0);
+        list.set_priority(slots[1], 1.0);
+        assert_eq!(list.remove_min(), Some((slots[0], slots[2], 1.0)));
+    }
+
+    #[test]
+    fn pop_front_takes_the_new_head_out_of_the_queue() {
+        let (mut list, slots) = list(4);
+        list.set_priority(slots[1], 1.0);
+        list.set_priority(slots[2], 2.0);
+        list.pop_front();
+        assert_eq!(list.min_priority(), Some(2.0));
+        assert_eq!(list.remove_min(), Some((slots[1], slots[3], 2.0)));
+        assert_eq!(list.remove_min(), None);
+        assert_eq!(lats(&list), [1.0, 3.0]);
+    }
+
+    #[test]
+    fn pop_back_takes_the_new_tail_out_of_the_queue() {
+        let (mut list, slots) = list(4);
+        list.set_priority(slots[1], 2.0);
+        list.set_priority(slots[2], 1.0);
+        list.pop_back();
+        assert_eq!(list.min_priority(), Some(2.0));
+        assert_eq!(list.remove_min(), Some((slots[0], slots[2], 2.0)));
+        assert_eq!(list.remove_min(), None);
+        assert_eq!(lats(&list), [0.0, 2.0]);
+    }
+
+    #[test]
+    fn reused_slots_do_not_revive_old_entries() {
+        let (mut list, slots) = list(3);
+        list.set_priority(slots[1], 1.0);
+        list.pop_back();
+        let slot = list.push_back(Point { lat: 3.0, lon: 0.0, time: 3.0, alt: None });
+        assert_eq!(slot, slots[2]);
+        // Point 1 is inner again, with a fresh entry; the stale one is gone
+        assert_eq!(list.remove_min(), Some((slots[0], slot, 1.0)));
+        assert_eq!(list.remove_min(), None);
+    }
+}
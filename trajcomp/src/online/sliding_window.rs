@@ -0,0 +1,64 @@
+use crate::error::Result;
+use crate::metric::ErrorMetric;
+use crate::online::OnlineCompressor;
+use crate::point::Point;
+
+/// Sliding (opening) window compression bounded by `epsilon` under `metric`.
+///
+/// The window starts at the last kept point (the anchor). When a point in
+/// the window has an error above `epsilon` against the segment anchor ->
+/// newest point, it becomes the new anchor and the window is re-checked from
+/// there. [`Opw`](crate::online::Opw) and [`OpwTr`](crate::online::OpwTr)
+/// are this window under PED and SED.
+#[derive(Debug, Clone)]
+pub struct SlidingWindow<M> {
+    pub(crate) metric: M,
+    pub(crate) epsilon: f64,
+    window: Vec<Point>,
+}
+
+impl<M: ErrorMetric> SlidingWindow<M> {
+    pub fn with_metric(metric: M, epsilon: f64) -> Self {
+        SlidingWindow { metric, epsilon, window: Vec::new() }
+    }
+
+    // First point inside the window that breaks the bound for end `e`
+    fn violation(&self, e: usize) -> Option<usize> {
+        let span = &self.window[..=e];
+        (1..e).find(|&i| self.metric.span_error(&span[0], &span[e], span, i) > self.epsilon)
+    }
+}
+
+impl<M: ErrorMetric> OnlineCompressor for SlidingWindow<M> {
+    fn push(&mut self, point: Point, out: &mut Vec<Point>) {
+        if self.window.is_empty() {
+            out.push(point);
+        }
+        self.window.push(point);
+
+        let mut e = self.window.len() - 1;
+        while e < self.window.len() {
+            if e >= 2 {
+                if let Some(i) = self.violation(e) {
+                    out.push(self.window[i]);
+                    self.window.drain(..i);
+                    e = 2;
+                    continue;
+                }
+            }
+            e += 1;
+        }
+    }
+
+    fn finish(&mut self, out: &mut Vec<Point>) {
+        if self.window.len() > 1 {
+            out.push(self.window[self.window.len() - 1]);
+        }
+        self.window.clear();
+    }
+}
+
+/// Compresses a whole trajectory with [`SlidingWindow`].
+pub fn sliding_window<M: ErrorMetric>(points: &[Point], metric: M, epsilon: f64) -> Result<Vec<Point>> {
+    SlidingWindow::with_metric(metric, epsilon).compress(points)
+}
@@ -0,0 +1,68 @@
+use crate::distance::DistanceModel;
+use crate::error::Result;
+use crate::online::priority_list::PriorityList;
+use crate::online::OnlineCompressor;
+use crate::point::Point;
+
+/// SQUISH: keeps a buffer of at most `max_buffer_size` points, evicting the
+/// point with the lowest accumulated SED whenever the buffer overflows.
+#[derive(Debug, Clone)]
+pub struct Squish {
+    max_buffer_size: usize,
+    model: DistanceModel,
+    buffer: PriorityList,
+}
+
+impl Squish {
+    pub fn new(max_buffer_size: usize) -> Self {
+        Squish { max_buffer_size, model: DistanceModel::Planar, buffer: PriorityList::with_capacity(max_buffer_size + 1) }
+    }
+
+    /// Measures distances with `model` instead of planar degrees.
+    pub fn with_model(mut self, model: DistanceModel) -> Self {
+        self.model = model;
+        self
+    }
+}
+
+impl OnlineCompressor for Squish {
+    fn push(&mut self, point: Point, out: &mut Vec<Point>) {
+        let model = self.model;
+        let buffer = &mut self.buffer;
+        // The first point is never evicted
+        if buffer.is_empty() {
+            out.push(point);
+        }
+        // With no room for inner points only the endpoints survive
+        if self.max_buffer_size <= 2 && buffer.len() == 2 {
+            buffer.pop_back();
+        }
+        let last = buffer.push_back(point);
+        if self.max_buffer_size <= 2 {
+            return;
+        }
+        // Compute SED for previous point
+        let Some(curr) = buffer.prev(last) else { return };
+        let Some(segment_start) = buffer.prev(curr) else { return };
+        let sed = buffer.priority(curr) + model.sed(buffer.point(segment_start), buffer.point(curr), &point);
+        buffer.set_priority(curr, sed);
+        // Buffer full, remove a point
+        if buffer.len() > self.max_buffer_size {
+            if let Some((prev, next, sed)) = buffer.remove_min() {
+                buffer.set_priority(prev, buffer.priority(prev) + sed);
+                buffer.set_priority(next, buffer.priority(next) + sed);
+            }
+        }
+    }
+
+    fn finish(&mut self, out: &mut Vec<Point>) {
+        out.extend(self.buffer.points().skip(1));
+        self.buffer.clear();
+    }
+}
+
+/// Runs SQUISH with a buffer of `cmp_ratio * points.len()` points.
+pub fn squish(points: &[Point], cmp_ratio: f64) -> Result<Vec<Point>> {
+    let max_buffer_size = (cmp_ratio * (points.len() as f64)) as usize;
+    Squish::new(max_buffer_size).compress(points)
+}
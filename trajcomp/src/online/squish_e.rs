@@ -0,0 +1,105 @@
+use crate::distance::DistanceModel;
+use crate::error::Result;
+use crate::online::priority_list::{PriorityList, Slot};
+use crate::online::OnlineCompressor;
+use crate::point::Point;
+
+/// SQUISH-E(λ, μ): compresses by at least `lambda` while reading the
+/// trajectory, then keeps removing points while the SED error stays under
+/// `mu`.
+///
+/// With `mu = 0` it is SQUISH-E(λ), which only bounds the ratio; with
+/// `lambda = 1` it is SQUISH-E(μ), which only bounds the error. The buffer
+/// grows to `n / lambda` points, so a run takes O(n log(n / lambda)).
+#[derive(Debug, Clone)]
+pub struct SquishE {
+    lambda: f64,
+    mu: f64,
+    model: DistanceModel,
+    buffer: PriorityList,
+    // Upper bound on the SED of the points removed next to each slot
+    pi: Vec<f64>,
+    capacity: usize,
+    index: usize,
+}
+
+impl SquishE {
+    pub fn new(lambda: f64, mu: f64) -> Self {
+        SquishE {
+            lambda,
+            mu,
+            model: DistanceModel::Planar,
+            buffer: PriorityList::default(),
+            pi: Vec::new(),
+            capacity: 4,
+            index: 0,
+        }
+    }
+
+    /// Measures distances with `model` instead of planar degrees.
+    pub fn with_model(mut self, model: DistanceModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    // Priority of an inner point: its own SED plus that of its removed
+    // neighbours
+    fn adjust_priority(&mut self, slot: Slot) {
+        let buffer = &self.buffer;
+        if let (Some(pre), Some(succ)) = (buffer.prev(slot), buffer.next(slot)) {
+            let priority = self.pi[slot] + self.model.sed(buffer.point(pre), buffer.point(slot), buffer.point(succ));
+            self.buffer.set_priority(slot, priority);
+        }
+    }
+
+    fn reduce(&mut self) {
+        if let Some((pre, succ, priority)) = self.buffer.remove_min() {
+            self.pi[pre] = self.pi[pre].max(priority);
+            self.pi[succ] = self.pi[succ].max(priority);
+            self.adjust_priority(pre);
+            self.adjust_priority(succ);
+        }
+    }
+}
+
+impl OnlineCompressor for SquishE {
+    fn push(&mut self, point: Point, out: &mut Vec<Point>) {
+        // The first point is never evicted
+        if self.buffer.is_empty() {
+            out.push(point);
+        }
+        // The buffer grows by one every `lambda` points, counting from one
+        self.index += 1;
+        if (self.index as f64) / self.lambda >= self.capacity as f64 {
+            self.capacity += 1;
+        }
+
+        let slot = self.buffer.push_back(point);
+        if slot >= self.pi.len() {
+            self.pi.resize(slot + 1, 0.0);
+        }
+        self.pi[slot] = 0.0;
+        if let Some(pre) = self.buffer.prev(slot) {
+            self.adjust_priority(pre);
+        }
+        if self.buffer.len() == self.capacity {
+            self.reduce();
+        }
+    }
+
+    fn finish(&mut self, out: &mut Vec<Point>) {
+        while self.buffer.min_priority().is_some_and(|priority| priority <= self.mu) {
+            self.reduce();
+        }
+        out.extend(self.buffer.points().skip(1));
+        self.buffer.clear();
+        self.pi.clear();
+        self.capacity = 4;
+        self.index = 0;
+    }
+}
+
+/// Compresses a whole trajectory with [`SquishE`].
+pub fn squish_e(points: &[Point], lambda: f64, mu: f64) -> Result<Vec<Point>> {
+    SquishE::new(lambda, mu).compress(points)
+}
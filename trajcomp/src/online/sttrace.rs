@@ -0,0 +1,74 @@
+use crate::distance::DistanceModel;
+use crate::error::Result;
+use crate::online::priority_list::PriorityList;
+use crate::online::OnlineCompressor;
+use crate::point::Point;
+
+/// STTrace: like SQUISH, but the SED of the neighbours of an evicted point is
+/// recomputed against their new neighbours instead of accumulated.
+#[derive(Debug, Clone)]
+pub struct STTrace {
+    max_buffer_size: usize,
+    model: DistanceModel,
+    buffer: PriorityList,
+}
+
+impl STTrace {
+    pub fn new(max_buffer_size: usize) -> Self {
+        STTrace { max_buffer_size, model: DistanceModel::Planar, buffer: PriorityList::with_capacity(max_buffer_size + 1) }
+    }
+
+    /// Measures distances with `model` instead of planar degrees.
+    pub fn with_model(mut self, model: DistanceModel) -> Self {
+        self.model = model;
+        self
+    }
+}
+
+impl OnlineCompressor for STTrace {
+    fn push(&mut self, point: Point, out: &mut Vec<Point>) {
+        let model = self.model;
+        let buffer = &mut self.buffer;
+        // The first point is never evicted
+        if buffer.is_empty() {
+            out.push(point);
+        }
+        // With no room for inner points only the endpoints survive
+        if self.max_buffer_size <= 2 && buffer.len() == 2 {
+            buffer.pop_back();
+        }
+        let last = buffer.push_back(point);
+        if self.max_buffer_size <= 2 {
+            return;
+        }
+        // Compute SED for previous point
+        let Some(curr) = buffer.prev(last) else { return };
+        let Some(segment_start) = buffer.prev(curr) else { return };
+        let sed = buffer.priority(curr) + model.sed(buffer.point(segment_start), buffer.point(curr), &point);
+        buffer.set_priority(curr, sed);
+        // Buffer full, remove a point
+        if buffer.len() > self.max_buffer_size {
+            if let Some((prev, next, _)) = buffer.remove_min() {
+                if let Some(prev_prev) = buffer.prev(prev) {
+                    let sed = model.sed(buffer.point(prev_prev), buffer.point(prev), buffer.point(next));
+                    buffer.set_priority(prev, sed);
+                }
+                if let Some(next_next) = buffer.next(next) {
+                    let sed = model.sed(buffer.point(prev), buffer.point(next), buffer.point(next_next));
+                    buffer.set_priority(next, sed);
+                }
+            }
+        }
+    }
+
+    fn finish(&mut self, out: &mut Vec<Point>) {
+        out.extend(self.buffer.points().skip(1));
+        self.buffer.clear();
+    }
+}
+
+/// Runs STTrace with a buffer of `cmp_ratio * points.len()` points.
+pub fn sttrace(points: &[Point], cmp_ratio: f64) -> Result<Vec<Point>> {
+    let max_buffer_size = (cmp_ratio * (points.len() as f64)) as usize;
+    STTrace::new(max_buffer_size).compress(points)
+}
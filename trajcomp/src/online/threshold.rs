@@ -0,0 +1,140 @@
+use crate::distance::{cacl_angle, cacl_haversine, DistanceModel};
+use crate::online::OnlineCompressor;
+use crate::error::Result;
+use crate::point::Point;
+
+fn cacl_distance(a: &Point, b: &Point, dataset: i32) -> f64 {
+    if dataset == 0 {
+        ((a.lat-b.lat).powi(2) + (a.lon-b.lon).powi(2)).sqrt()
+    } else if dataset == 1 {
+        ((a.lat/1000.0 - b.lat/1000.0).powi(2) + (a.lon/1000.0 - b.lon/1000.0).powi(2)).sqrt()
+    } else {
+        cacl_haversine(a, b)
+    }
+}
+
+// Distances and headings either from the legacy `dataset` switch or from a
+// distance model
+#[derive(Debug, Copy, Clone)]
+enum Geometry {
+    Dataset(i32),
+    Model(DistanceModel),
+}
+
+impl Geometry {
+    fn distance(&self, a: &Point, b: &Point) -> f64 {
+        match self {
+            Geometry::Dataset(dataset) => cacl_distance(a, b, *dataset),
+            Geometry::Model(model) => model.distance(a, b),
+        }
+    }
+
+    fn angle(&self, a: &Point, b: &Point) -> f64 {
+        match self {
+            Geometry::Dataset(_) => cacl_angle(a, b),
+            Geometry::Model(model) => model.heading(a, b),
+        }
+    }
+}
+
+fn cacl_speed(a: &Point, b: &Point, geometry: Geometry) -> f64 {
+    geometry.distance(a,b)/(b.time-a.time)
+}
+
+fn safe_speed(sample_b:&Point,sample_c:&Point,point_c:&Point,point_d:&Point,point_e:&Point,geometry:Geometry,speed_threshold:f64) -> bool {
+    let sample_speed = cacl_speed(sample_b,sample_c,geometry);
+    let trajectory_speed = cacl_speed(point_c,point_d,geometry);
+    let de_speed = cacl_speed(point_d,point_e,geometry);
+    !((sample_speed-de_speed).abs() > speed_threshold || (trajectory_speed-de_speed).abs() > speed_threshold)
+}
+
+fn safe_orientation(sample_b:&Point,sample_c:&Point,point_c:&Point,point_d:&Point,point_e:&Point,geometry:Geometry,ori_threshold:f64) -> bool {
+    let angle_sample_bc = geometry.angle(sample_b, sample_c);
+    let angle_de = geometry.angle(point_d, point_e);
+    let angle_sample_bc_de = angle_de - angle_sample_bc;
+    let angle_trajectory_cd = geometry.angle(point_c, point_d);
+    let angle_trajectory_cd_de = angle_de - angle_trajectory_cd;
+    !(angle_sample_bc_de.abs() > ori_threshold || angle_trajectory_cd_de.abs() > ori_threshold)
+}
+
+/// Threshold-guided sampling: a point is kept when the speed or heading of
+/// the incoming segment deviates from both the sampled and the original
+/// trajectory by more than the given thresholds.
+///
+/// `dataset` selects the distance used for speeds: 0 for planar degrees,
+/// 1 for planar coordinates scaled by 1/1000, anything else for haversine.
+/// [`Threshold::with_model`] replaces it with a distance model.
+#[derive(Debug, Clone)]
+pub struct Threshold {
+    speed_threshold: f64,
+    geometry: Geometry,
+    ori_threshold: f64,
+    // Last two kept points
+    sample: Vec<Point>,
+    // Last two original points
+    recent: Vec<Point>,
+    last_kept: bool,
+}
+
+impl Threshold {
+    pub fn new(speed_threshold: f64, dataset: i32, ori_threshold: f64) -> Self {
+        Threshold {
+            speed_threshold,
+            geometry: Geometry::Dataset(dataset),
+            ori_threshold,
+            sample: Vec::with_capacity(2),
+            recent: Vec::with_capacity(2),
+            last_kept: false,
+        }
+    }
+
+    /// Measures speeds and headings with `model` instead of `dataset`.
+    pub fn with_model(mut self, model: DistanceModel) -> Self {
+        self.geometry = Geometry::Model(model);
+        self
+    }
+
+    fn keep(&mut self, point: Point, out: &mut Vec<Point>) {
+        out.push(point);
+        if self.sample.len() == 2 {
+            self.sample.remove(0);
+        }
+        self.sample.push(point);
+        self.last_kept = true;
+    }
+}
+
+impl OnlineCompressor for Threshold {
+    fn push(&mut self, point: Point, out: &mut Vec<Point>) {
+        if self.recent.len() < 2 {
+            self.keep(point, out);
+        } else {
+            let has_safe_speed = safe_speed(&self.sample[0], &self.sample[1], &self.recent[0], &self.recent[1], &point, self.geometry, self.speed_threshold);
+            let has_safe_orientation = safe_orientation(&self.sample[0], &self.sample[1], &self.recent[0], &self.recent[1], &point, self.geometry, self.ori_threshold);
+            if has_safe_speed && has_safe_orientation {
+                self.last_kept = false;
+            } else {
+                self.keep(point, out);
+            }
+            self.recent.remove(0);
+        }
+        self.recent.push(point);
+    }
+
+    fn finish(&mut self, out: &mut Vec<Point>) {
+        // The last point is always kept
+        if !self.last_kept {
+            if let Some(last) = self.recent.last() {
+                out.push(*last);
+            }
+        }
+        self.sample.clear();
+        self.recent.clear();
+        self.last_kept = false;
+    }
+}
+
+/// Compresses a whole trajectory with [`Threshold`].
+pub fn threshold(points: &[Point], speed_threshold: f64, dataset: i32, ori_threshold: f64) -> Result<Vec<Point>> {
+    Threshold::new(speed_threshold, dataset, ori_threshold).compress(points)
+}
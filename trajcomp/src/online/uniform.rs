@@ -0,0 +1,42 @@
+use crate::online::OnlineCompressor;
+use crate::error::Result;
+use crate::point::Point;
+
+/// Keeps every `eps`-th point plus the last one.
+#[derive(Debug, Clone)]
+pub struct Uniform {
+    eps: usize,
+    index: usize,
+    last: Option<Point>,
+}
+
+impl Uniform {
+    pub fn new(eps: usize) -> Self {
+        Uniform { eps: eps.max(1), index: 0, last: None }
+    }
+}
+
+impl OnlineCompressor for Uniform {
+    fn push(&mut self, point: Point, out: &mut Vec<Point>) {
+        if self.index.is_multiple_of(self.eps) {
+            out.push(point);
+            self.last = None;
+        } else {
+            self.last = Some(point);
+        }
+        self.index += 1;
+    }
+
+    fn finish(&mut self, out: &mut Vec<Point>) {
+        // The last point is kept even if it is off the grid
+        if let Some(last) = self.last.take() {
+            out.push(last);
+        }
+        self.index = 0;
+    }
+}
+
+/// Compresses a whole trajectory with [`Uniform`].
+pub fn uniform(points: &[Point], eps: usize) -> Result<Vec<Point>> {
+    Uniform::new(eps).compress(points)
+}
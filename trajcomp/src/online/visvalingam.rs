@@ -0,0 +1,87 @@
+use crate::distance::DistanceModel;
+use crate::error::Result;
+use crate::offline::visvalingam::{limit_reached, remove_smallest, set_area, VwLimit};
+use crate::online::priority_list::PriorityList;
+use crate::online::OnlineCompressor;
+use crate::point::Point;
+
+/// Buffer size of [`BufferedVisvalingam`] under an area threshold, unless
+/// set with [`BufferedVisvalingam::with_buffer_size`].
+pub const DEFAULT_BUFFER_SIZE: usize = 1000;
+
+/// Visvalingam-Whyatt over a bounded buffer, in the manner of SQUISH.
+///
+/// With a point budget the buffer holds the result, evicting the point with
+/// the smallest effective area whenever it overflows. With an area threshold
+/// points are evicted as soon as their effective area falls below it, and
+/// when the buffer is full its oldest point becomes final.
+#[derive(Debug, Clone)]
+pub struct BufferedVisvalingam {
+    limit: VwLimit,
+    buffer_size: usize,
+    model: DistanceModel,
+    buffer: PriorityList,
+}
+
+impl BufferedVisvalingam {
+    pub fn new(limit: VwLimit) -> Self {
+        let buffer_size = match limit {
+            VwLimit::Points(max_points) => max_points,
+            VwLimit::Area(_) => DEFAULT_BUFFER_SIZE,
+        };
+        BufferedVisvalingam {
+            limit,
+            buffer_size,
+            model: DistanceModel::Planar,
+            buffer: PriorityList::with_capacity(buffer_size + 1),
+        }
+    }
+
+    /// Holds at most `buffer_size` points, and at least three, under an area
+    /// threshold. A point budget is the buffer size.
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        if let VwLimit::Area(_) = self.limit {
+            self.buffer_size = buffer_size.max(3);
+            self.buffer = PriorityList::with_capacity(self.buffer_size + 1);
+        }
+        self
+    }
+
+    /// Measures areas with `model` instead of planar degrees.
+    pub fn with_model(mut self, model: DistanceModel) -> Self {
+        self.model = model;
+        self
+    }
+}
+
+impl OnlineCompressor for BufferedVisvalingam {
+    fn push(&mut self, point: Point, out: &mut Vec<Point>) {
+        let model = self.model;
+        let buffer = &mut self.buffer;
+        // The first point is never evicted
+        if buffer.is_empty() {
+            out.push(point);
+        }
+        let last = buffer.push_back(point);
+        // The previous point now has neighbours on both sides
+        if let Some(curr) = buffer.prev(last) {
+            let floor = buffer.priority(curr);
+            set_area(buffer, curr, floor, model);
+        }
+        while !limit_reached(buffer, self.limit) && remove_smallest(buffer, model) {}
+        if buffer.len() > self.buffer_size.max(3) {
+            buffer.pop_front();
+            out.extend(buffer.points().next());
+        }
+    }
+
+    fn finish(&mut self, out: &mut Vec<Point>) {
+        out.extend(self.buffer.points().skip(1));
+        self.buffer.clear();
+    }
+}
+
+/// Compresses a whole trajectory with [`BufferedVisvalingam`].
+pub fn buffered_visvalingam(points: &[Point], limit: VwLimit) -> Result<Vec<Point>> {
+    BufferedVisvalingam::new(limit).compress(points)
+}
@@ -0,0 +1,26 @@
+use crate::error::{Error, Result};
+
+/// A single GPS fix: latitude and longitude in degrees, time in seconds.
+///
+/// `alt` is the altitude in metres, when the source provides one.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct Point {
+    pub lat: f64,
+    pub lon: f64,
+    pub time: f64,
+    pub alt: Option<f64>,
+}
+
+/// Checks that a trajectory has points and that time never goes backwards.
+/// Equal consecutive timestamps are allowed.
+pub fn check_trajectory(points: &[Point]) -> Result<()> {
+    if points.is_empty() {
+        return Err(Error::EmptyTrajectory);
+    }
+    for (i, pair) in points.windows(2).enumerate() {
+        if pair[1].time < pair[0].time {
+            return Err(Error::NonMonotonicTime { point: i + 2, previous: pair[0].time, time: pair[1].time });
+        }
+    }
+    Ok(())
+}
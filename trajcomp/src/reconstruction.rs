@@ -0,0 +1,99 @@
+//! Positions between the kept points of a compressed trajectory.
+//!
+//! The vehicle is assumed to move at constant speed between two kept points,
+//! which is the same assumption the SED-bounded compressors make, so the
+//! position at any time is off from the original by at most their bound.
+
+use crate::distance::interpolate;
+use crate::point::Point;
+
+/// Position at `time`, or `None` outside the time span of `points`.
+///
+/// `points` must be ordered by time. With several points at `time`, the last
+/// one is returned.
+pub fn position_at(points: &[Point], time: f64) -> Option<Point> {
+    let first = points.first()?;
+    let last = points.last()?;
+    if !(first.time..=last.time).contains(&time) {
+        return None;
+    }
+    // First point after `time`
+    let i = points.partition_point(|p| p.time <= time);
+    if i == points.len() {
+        return Some(Point { time, ..*last });
+    }
+    Some(interpolate(&points[i - 1], &points[i], time))
+}
+
+/// Positions every `interval` seconds from the first point on, up to the
+/// last one.
+///
+/// # Panics
+///
+/// If `interval` is not positive.
+pub fn resample(points: &[Point], interval: f64) -> Vec<Point> {
+    assert!(interval > 0.0, "resampling interval must be positive, got {}", interval);
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return Vec::new();
+    };
+    let steps = ((last.time - first.time) / interval).floor() as usize;
+    let mut out = Vec::with_capacity(steps + 1);
+    // Segment that the current time falls in, advanced monotonically
+    let mut i = 0;
+    for step in 0..=steps {
+        let time = first.time + step as f64 * interval;
+        while i + 1 < points.len() && points[i + 1].time <= time {
+            i += 1;
+        }
+        out.push(match points.get(i + 1) {
+            Some(next) => interpolate(&points[i], next, time),
+            None => Point { time, ..points[i] },
+        });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(lat: f64, lon: f64, time: f64) -> Point {
+        Point { lat, lon, time, alt: None }
+    }
+
+    #[test]
+    fn vertex_time_gives_the_vertex() {
+        let points = [point(0.0, 0.0, 0.0), point(2.0, 4.0, 10.0), point(3.0, 4.0, 20.0)];
+        for p in points {
+            assert_eq!(position_at(&points, p.time), Some(p));
+        }
+        assert_eq!(position_at(&points, 5.0), Some(point(1.0, 2.0, 5.0)));
+    }
+
+    #[test]
+    fn nothing_outside_the_time_span() {
+        let points = [point(0.0, 0.0, 10.0), point(2.0, 4.0, 20.0)];
+        assert_eq!(position_at(&points, 9.5), None);
+        assert_eq!(position_at(&points, 20.5), None);
+        assert_eq!(position_at(&[], 10.0), None);
+    }
+
+    #[test]
+    fn repeated_timestamp_gives_the_last_point() {
+        let points = [point(0.0, 0.0, 0.0), point(1.0, 1.0, 10.0), point(2.0, 2.0, 10.0), point(4.0, 2.0, 20.0)];
+        assert_eq!(position_at(&points, 10.0), Some(points[2]));
+        assert_eq!(position_at(&points, 15.0), Some(point(3.0, 2.0, 15.0)));
+
+        let still = [point(1.0, 1.0, 10.0), point(2.0, 2.0, 10.0)];
+        assert_eq!(position_at(&still, 10.0), Some(still[1]));
+        assert_eq!(resample(&still, 1.0), [still[1]]);
+    }
+
+    #[test]
+    fn resampling_stops_before_an_uneven_end() {
+        let points = [point(0.0, 0.0, 0.0), point(10.0, 0.0, 10.0)];
+        let resampled = resample(&points, 4.0);
+        assert_eq!(resampled, [point(0.0, 0.0, 0.0), point(4.0, 0.0, 4.0), point(8.0, 0.0, 8.0)]);
+        assert!(resampled.iter().all(|p| p.lat.is_finite() && p.lon.is_finite()));
+    }
+}
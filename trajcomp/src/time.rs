@@ -0,0 +1,148 @@
+//! Calendar conversions for the readers and writers, on the proleptic
+//! Gregorian calendar in UTC.
+
+/// Days since 1970-01-01 of a civil date.
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Parses `YYYY-MM-DD` and `HH:MM:SS[.fff]` into seconds since the epoch.
+pub(crate) fn parse_date_time(date: &str, time: &str) -> Option<f64> {
+    let mut ymd = date.trim().splitn(3, '-');
+    let year: i64 = ymd.next()?.parse().ok()?;
+    let month: i64 = ymd.next()?.parse().ok()?;
+    let day: i64 = ymd.next()?.parse().ok()?;
+    let mut hms = time.trim().splitn(3, ':');
+    let hour: f64 = hms.next()?.parse().ok()?;
+    let minute: f64 = hms.next()?.parse().ok()?;
+    let second: f64 = hms.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let days = days_from_civil(year, month, day);
+    Some(days as f64 * 86400.0 + hour * 3600.0 + minute * 60.0 + second)
+}
+
+/// Civil date of a number of days since 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = if month <= 2 { yoe + era * 400 + 1 } else { yoe + era * 400 };
+    (year, month, day)
+}
+
+/// Parses an ISO 8601 timestamp such as `2008-10-23T02:53:04Z` or
+/// `2008-10-23T10:53:04.5+08:00` into seconds since the epoch. The offset
+/// from UTC is `Z`, `±hh`, `±hhmm` or `±hh:mm`.
+pub(crate) fn parse_iso8601(timestamp: &str) -> Option<f64> {
+    let (date, rest) = timestamp.trim().split_once(['T', ' '])?;
+    let (time, offset) = if let Some(time) = rest.strip_suffix('Z') {
+        (time, 0.0)
+    } else if let Some(sign_at) = rest.rfind(['+', '-']) {
+        let sign = if rest[sign_at..].starts_with('-') { -1.0 } else { 1.0 };
+        (&rest[..sign_at], sign * parse_offset(&rest[sign_at + 1..])?)
+    } else {
+        (rest, 0.0)
+    };
+    Some(parse_date_time(date, time)? - offset)
+}
+
+// Seconds of an unsigned `hh`, `hhmm` or `hh:mm` offset
+fn parse_offset(offset: &str) -> Option<f64> {
+    let (hours, minutes) = match offset.len() {
+        2 => (offset, "00"),
+        4 => offset.split_at_checked(2)?,
+        5 => offset.split_once(':').filter(|(hours, _)| hours.len() == 2)?,
+        _ => return None,
+    };
+    if !hours.bytes().chain(minutes.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes): (u32, u32) = (hours.parse().ok()?, minutes.parse().ok()?);
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(f64::from(hours * 3600 + minutes * 60))
+}
+
+/// Formats seconds since the epoch as an ISO 8601 UTC timestamp, with
+/// milliseconds only when the time is not a whole second.
+pub(crate) fn format_iso8601(time: f64) -> String {
+    let whole = time.floor();
+    let millis = ((time - whole) * 1000.0).round() as i64;
+    let (whole, millis) = if millis == 1000 { (whole + 1.0, 0) } else { (whole, millis) };
+    let secs = whole as i64;
+    let (year, month, day) = civil_from_days(secs.div_euclid(86400));
+    let of_day = secs.rem_euclid(86400);
+    let (hour, minute, second) = (of_day / 3600, of_day % 3600 / 60, of_day % 60);
+    if millis == 0 {
+        format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, hour, minute, second)
+    } else {
+        format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z", year, month, day, hour, minute, second, millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2008-10-23T02:53:04Z
+    const T: f64 = 1224730384.0;
+
+    #[test]
+    fn parses_dates_and_times() {
+        assert_eq!(parse_date_time("2008-10-23", "02:53:04"), Some(T));
+        assert_eq!(parse_date_time("2008-10-23", "02:53:04.25"), Some(T + 0.25));
+        assert_eq!(parse_date_time("1969-12-31", "23:59:59"), Some(-1.0));
+        assert_eq!(parse_date_time("2008-13-23", "02:53:04"), None);
+        assert_eq!(parse_date_time("2008-10-23", "02:53"), None);
+    }
+
+    #[test]
+    fn parses_utc_and_fractional_seconds() {
+        assert_eq!(parse_iso8601("2008-10-23T02:53:04Z"), Some(T));
+        assert_eq!(parse_iso8601(" 2008-10-23 02:53:04 "), Some(T));
+        assert_eq!(parse_iso8601("2008-10-23T02:53:04.5Z"), Some(T + 0.5));
+        assert_eq!(parse_iso8601("2008-10-23"), None);
+    }
+
+    #[test]
+    fn parses_offsets() {
+        for offset in ["+08", "+0800", "+08:00"] {
+            assert_eq!(parse_iso8601(&format!("2008-10-23T10:53:04{}", offset)), Some(T), "{}", offset);
+        }
+        assert_eq!(parse_iso8601("2008-10-22T21:23:04.5-05:30"), Some(T + 0.5));
+        assert_eq!(parse_iso8601("2008-10-22T21:23:04-0530"), Some(T));
+        assert_eq!(parse_iso8601("2008-10-23T02:53:04+00"), Some(T));
+    }
+
+    #[test]
+    fn rejects_malformed_offsets() {
+        for offset in ["+8", "+080", "+08:0", "+8:00", "+08000", "+24:00", "+08:60", "+0a00", "+08-00", "+"] {
+            assert_eq!(parse_iso8601(&format!("2008-10-23T10:53:04{}", offset)), None, "{}", offset);
+        }
+    }
+
+    #[test]
+    fn formats_and_parses_back() {
+        assert_eq!(format_iso8601(T), "2008-10-23T02:53:04Z");
+        assert_eq!(format_iso8601(T + 0.25), "2008-10-23T02:53:04.250Z");
+        assert_eq!(format_iso8601(T + 0.9999), "2008-10-23T02:53:05Z");
+        assert_eq!(format_iso8601(-0.5), "1969-12-31T23:59:59.500Z");
+        for time in [T, T + 0.125, 0.0, -86400.0, 951782400.0] {
+            assert_eq!(parse_iso8601(&format_iso8601(time)), Some(time));
+        }
+    }
+}
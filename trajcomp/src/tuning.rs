@@ -0,0 +1,114 @@
+//! Picking the parameter of an error-bounded compressor from a storage
+//! budget instead of a tolerance.
+
+use crate::error::Result;
+use crate::point::Point;
+
+/// An epsilon found by [`search_epsilon`] and the points it keeps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpsilonSearch {
+    pub epsilon: f64,
+    pub compressed: Vec<Point>,
+    /// Whether `compressed` fits the budget. When it does not, `epsilon` is
+    /// only the largest one tried.
+    pub reached: bool,
+}
+
+// Bisection steps after the bracketing; enough to pin epsilon down to a
+// relative 1e-9 from any starting bracket
+const MAX_STEPS: usize = 100;
+const MAX_DOUBLINGS: usize = 64;
+
+/// Finds the smallest epsilon for which `compress(points, epsilon)` keeps at
+/// most `target_ratio` of the points, like the `cmp_ratio` of SQUISH.
+///
+/// The search brackets epsilon by doubling from 1 and then bisects, so it
+/// assumes fewer points are kept as epsilon grows, which holds for OPW,
+/// OPW-TR, dead reckoning, Douglas-Peucker and TD-TR all but in rare ties.
+/// When no epsilon reaches the target, e.g. because the endpoints are always
+/// kept, the largest epsilon tried is returned, with `reached` false.
+pub fn search_epsilon<F>(points: &[Point], target_ratio: f64, mut compress: F) -> Result<EpsilonSearch>
+where F: FnMut(&[Point], f64) -> Result<Vec<Point>>, {
+    let budget = (target_ratio * points.len() as f64).floor() as usize;
+    let mut run = |epsilon: f64| {
+        compress(points, epsilon).map(|compressed| EpsilonSearch { epsilon, reached: compressed.len() <= budget, compressed })
+    };
+
+    let at_zero = run(0.0)?;
+    if at_zero.reached {
+        return Ok(at_zero);
+    }
+    // Largest epsilon known to keep too many points, and smallest known to fit
+    let mut low = 0.0;
+    let mut high = run(1.0)?;
+    for _ in 0..MAX_DOUBLINGS {
+        if high.reached {
+            break;
+        }
+        low = high.epsilon;
+        high = run(high.epsilon * 2.0)?;
+    }
+    if !high.reached {
+        return Ok(high);
+    }
+
+    for _ in 0..MAX_STEPS {
+        if high.epsilon - low <= high.epsilon * 1e-9 {
+            break;
+        }
+        let middle = run(low + (high.epsilon - low) / 2.0)?;
+        if middle.reached {
+            high = middle;
+        } else {
+            low = middle.epsilon;
+        }
+    }
+    Ok(high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::offline::douglas_peucker;
+
+    fn trajectory(lons: &[f64]) -> Vec<Point> {
+        lons.iter().enumerate().map(|(i, &lon)| Point { lat: i as f64, lon, time: i as f64, alt: None }).collect()
+    }
+
+    #[test]
+    fn finds_the_smallest_epsilon_reaching_the_target() {
+        let points = trajectory(&[0.0, 0.3, 0.0, 1.5, 0.0, 0.7, 0.0, 2.0, 0.0]);
+        let search = search_epsilon(&points, 0.5, douglas_peucker).unwrap();
+        assert!(search.reached);
+        assert!(search.compressed.len() <= 4);
+        assert_eq!(search.compressed, douglas_peucker(&points, search.epsilon).unwrap());
+        assert!(douglas_peucker(&points, search.epsilon * (1.0 - 1e-6)).unwrap().len() > 4);
+    }
+
+    #[test]
+    fn reports_an_unreachable_target() {
+        // Douglas-Peucker always keeps both endpoints
+        let points = trajectory(&[0.0, 1.0, 0.0, 1.0, 0.0]);
+        let search = search_epsilon(&points, 0.2, douglas_peucker).unwrap();
+        assert!(!search.reached);
+        assert_eq!(search.compressed, [points[0], points[4]]);
+    }
+
+    #[test]
+    fn full_ratio_keeps_every_point() {
+        let points = trajectory(&[0.0, 1.0, 0.0, 1.0, 0.0]);
+        let search = search_epsilon(&points, 1.0, douglas_peucker).unwrap();
+        assert!(search.reached);
+        assert_eq!(search.epsilon, 0.0);
+        assert_eq!(search.compressed, points);
+    }
+
+    #[test]
+    fn two_points_cannot_be_halved() {
+        let points = trajectory(&[0.0, 1.0]);
+        let search = search_epsilon(&points, 0.5, douglas_peucker).unwrap();
+        assert!(!search.reached);
+        assert_eq!(search.compressed, points);
+        assert!(search.epsilon.is_finite());
+    }
+}
@@ -0,0 +1,30 @@
+//! Traces shared by the integration tests.
+
+// Every test file compiles its own copy and uses only part of it
+#![allow(dead_code)]
+
+use trajcomp::point::Point;
+
+/// The GeoLife trace bundled with the OPW binary.
+pub const GEOLIFE_PLT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../online/opw/data/20081023025304-0.plt");
+
+/// A trajectory through `positions`, one second apart.
+pub fn trajectory(positions: &[(f64, f64)]) -> Vec<Point> {
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, &(lat, lon))| Point { lat, lon, time: i as f64, alt: None })
+        .collect()
+}
+
+/// A trajectory from (40, 116) taking one `(dlat, dlon)` step per second.
+/// A step of 1e-5 degree of latitude is about 1.1 m.
+pub fn walk(steps: &[(f64, f64)]) -> Vec<Point> {
+    let mut position = (40.0, 116.0);
+    let mut positions = vec![position];
+    for &(dlat, dlon) in steps {
+        position = (position.0 + dlat, position.1 + dlon);
+        positions.push(position);
+    }
+    trajectory(&positions)
+}
@@ -0,0 +1,45 @@
+//! Velocity dead reckoning keeps the points that stray from the position
+//! predicted by the speed and heading at the last kept point.
+
+use trajcomp::distance::DistanceModel;
+use trajcomp::io::gpsreader;
+use trajcomp::online::{velocity_dead_reckoning, OnlineCompressor, VelocityDeadReckoning};
+use trajcomp::Algorithm;
+
+mod common;
+use common::{walk, GEOLIFE_PLT};
+
+#[test]
+fn constant_velocity_keeps_the_endpoints() {
+    let points = walk(&[(1e-4, 1e-4); 50]);
+    let kept = velocity_dead_reckoning(&points, 1.0).unwrap();
+    assert_eq!(kept, [points[0], points[50]]);
+}
+
+#[test]
+fn turn_keeps_the_first_point_off_the_prediction() {
+    // 10 s north at about 11 m/s, then 10 s east
+    let mut steps = vec![(1e-4, 0.0); 10];
+    steps.extend([(0.0, 1e-4); 10]);
+    let points = walk(&steps);
+    let kept = velocity_dead_reckoning(&points, 5.0).unwrap();
+    // One second after the turn the point is some 15 m off, and the heading
+    // taken there predicts the rest
+    assert_eq!(kept, [points[0], points[11], points[20]]);
+}
+
+#[test]
+fn algorithm_threshold_is_in_metres_under_the_planar_model() {
+    let points = gpsreader(GEOLIFE_PLT).unwrap();
+    let algorithm = Algorithm::VelocityDeadReckoning { epsilon: 25.0 };
+    let planar = algorithm.compress(&points, DistanceModel::Planar).unwrap();
+    assert_eq!(planar, algorithm.compress(&points, DistanceModel::Haversine).unwrap());
+    assert!(planar.len() > 2);
+}
+
+#[test]
+fn planar_model_keeps_the_threshold_in_metres() {
+    let points = gpsreader(GEOLIFE_PLT).unwrap();
+    let planar = VelocityDeadReckoning::new(25.0).with_model(DistanceModel::Planar).compress(&points).unwrap();
+    assert_eq!(planar, velocity_dead_reckoning(&points, 25.0).unwrap());
+}
@@ -0,0 +1,129 @@
+//! Randomized checks of the guarantees of the error-bounded compressors, OPW
+//! and OPW-TR and the sliding window and bottom-up ones under every metric:
+//! the first and last points are kept, kept points stay in input order, and
+//! every discarded point lies within the tolerance of the output segment
+//! enclosing it, under the compressor's metric.
+//!
+//! The generated trajectories revisit earlier positions, stand still and
+//! repeat timestamps, so zero-length segments and zero durations come up.
+
+use proptest::prelude::*;
+use proptest::sample::Index;
+use trajcomp::distance::DistanceModel;
+use trajcomp::metric::{ErrorMetric, Metric, Ped, Sed};
+use trajcomp::offline::bottom_up;
+use trajcomp::online::{sliding_window, OnlineCompressor, Opw, OpwTr};
+use trajcomp::point::Point;
+
+#[derive(Debug, Clone)]
+enum Step {
+    // Moves by (lat, lon) degrees
+    Move(f64, f64),
+    // Repeats the current position
+    Stay,
+    // Goes back to the position of an earlier point
+    Revisit(Index),
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    prop_oneof![
+        4 => (-1e-3..1e-3, -1e-3..1e-3).prop_map(|(lat, lon)| Step::Move(lat, lon)),
+        1 => Just(Step::Stay),
+        1 => any::<Index>().prop_map(Step::Revisit),
+    ]
+}
+
+// Seconds to the next point, often none at all
+fn interval() -> impl Strategy<Value = f64> {
+    prop_oneof![1 => Just(0.0), 3 => 1.0..60.0]
+}
+
+// Each point carries its input index in `alt`, which the compressors copy
+// through untouched, so kept points can be traced back to the input
+fn trajectory() -> impl Strategy<Value = Vec<Point>> {
+    let start = (-80.0..80.0, -179.0..179.0);
+    (start, prop::collection::vec((step(), interval()), 0..80)).prop_map(|((lat, lon), steps)| {
+        let mut points = vec![Point { lat, lon, time: 0.0, alt: Some(0.0) }];
+        for (step, dt) in steps {
+            let last = points[points.len() - 1];
+            let (lat, lon) = match step {
+                Step::Move(dlat, dlon) => (last.lat + dlat, last.lon + dlon),
+                Step::Stay => (last.lat, last.lon),
+                Step::Revisit(index) => {
+                    let earlier = points[index.index(points.len())];
+                    (earlier.lat, earlier.lon)
+                }
+            };
+            points.push(Point { lat, lon, time: last.time + dt, alt: Some(points.len() as f64) });
+        }
+        points
+    })
+}
+
+// Planar tolerances are in degrees, the others in metres
+fn model_and_epsilon() -> impl Strategy<Value = (DistanceModel, f64)> {
+    prop_oneof![
+        (1e-6..1e-3).prop_map(|epsilon| (DistanceModel::Planar, epsilon)),
+        (0.1..100.0).prop_map(|epsilon| (DistanceModel::Equirectangular, epsilon)),
+        (0.1..100.0).prop_map(|epsilon| (DistanceModel::Haversine, epsilon)),
+    ]
+}
+
+// A metric with a tolerance in its unit, radians for DAD
+fn metric_and_epsilon() -> impl Strategy<Value = (Metric, DistanceModel, f64)> {
+    (prop::sample::select(Metric::ALL.to_vec()), model_and_epsilon(), 0.01..3.0).prop_map(
+        |(metric, (model, epsilon), radians)| match metric {
+            Metric::Dad => (metric, model, radians),
+            _ => (metric, model, epsilon),
+        },
+    )
+}
+
+fn check_guarantees(points: &[Point], kept: &[Point], epsilon: f64, metric: impl ErrorMetric) -> Result<(), TestCaseError> {
+    let indices: Vec<usize> = kept.iter().map(|point| point.alt.unwrap() as usize).collect();
+    prop_assert_eq!(indices[0], 0);
+    prop_assert_eq!(indices[indices.len() - 1], points.len() - 1);
+    if points.len() > 1 {
+        prop_assert!(indices.len() >= 2);
+    }
+    for (point, &i) in kept.iter().zip(&indices) {
+        prop_assert_eq!(point, &points[i]);
+    }
+    for pair in indices.windows(2) {
+        let (s, e) = (pair[0], pair[1]);
+        prop_assert!(s < e, "kept indices out of order: {:?}", indices);
+        for m in s + 1..e {
+            let d = metric.span_error(&points[s], &points[e], &points[s..=e], m - s);
+            prop_assert!(d <= epsilon, "point {} is {} from segment {} -> {}, over {}", m, d, s, e, epsilon);
+        }
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+
+    #[test]
+    fn opw_bounds_ped(points in trajectory(), (model, epsilon) in model_and_epsilon()) {
+        let kept = Opw::new(epsilon).with_model(model).compress(&points).unwrap();
+        check_guarantees(&points, &kept, epsilon, Ped(model))?;
+    }
+
+    #[test]
+    fn opw_tr_bounds_sed(points in trajectory(), (model, epsilon) in model_and_epsilon()) {
+        let kept = OpwTr::new(epsilon).with_model(model).compress(&points).unwrap();
+        check_guarantees(&points, &kept, epsilon, Sed(model))?;
+    }
+
+    #[test]
+    fn sliding_window_bounds((metric, model, epsilon) in metric_and_epsilon(), points in trajectory()) {
+        let kept = sliding_window(&points, metric.with_model(model), epsilon).unwrap();
+        check_guarantees(&points, &kept, epsilon, metric.with_model(model))?;
+    }
+
+    #[test]
+    fn bottom_up_bounds((metric, model, epsilon) in metric_and_epsilon(), points in trajectory()) {
+        let kept = bottom_up(&points, metric.with_model(model), epsilon).unwrap();
+        check_guarantees(&points, &kept, epsilon, metric.with_model(model))?;
+    }
+}
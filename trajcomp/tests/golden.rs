@@ -0,0 +1,124 @@
+//! Every compressor on the bundled GeoLife trace, with the parameters of its
+//! `.sh` script, must reproduce the CSV committed next to it byte for byte.
+//!
+//! After a deliberate change of output, rerun the script to regenerate the
+//! CSV and commit it with the change.
+
+use std::fs;
+use std::path::PathBuf;
+
+use trajcomp::io::{gpsreader, write_csv_to};
+use trajcomp::offline::{douglas_peucker, td_tr, visvalingam, VwLimit};
+use trajcomp::online::{
+    buffered_visvalingam, dead_reckoning, opw, opw_tr, squish, squish_e, sttrace, threshold, uniform,
+};
+use trajcomp::point::Point;
+use trajcomp::Result;
+
+const TRACE: &str = "20081023025304-0.plt";
+
+// The data directory of the binary crate `crate_dir`, e.g. `online/opw`
+fn data_dir(crate_dir: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(crate_dir).join("data")
+}
+
+// Compresses the trace of `crate_dir` and compares the CSV output against
+// `expected` in the same directory, reporting the first differing line
+fn check(crate_dir: &str, expected: &str, compress: impl Fn(&[Point]) -> Result<Vec<Point>>) {
+    let data = data_dir(crate_dir);
+    let points = gpsreader(data.join(TRACE).to_str().unwrap()).unwrap();
+    let compressed = compress(&points).unwrap();
+    let mut actual = Vec::new();
+    write_csv_to(&compressed, &mut actual).unwrap();
+    let actual = String::from_utf8(actual).unwrap();
+
+    let expected_path = data.join(expected);
+    let expected = fs::read_to_string(&expected_path).unwrap();
+    if actual == expected {
+        return;
+    }
+    let (actual_lines, expected_lines): (Vec<&str>, Vec<&str>) = (actual.lines().collect(), expected.lines().collect());
+    let line = actual_lines
+        .iter()
+        .zip(&expected_lines)
+        .position(|(a, e)| a != e)
+        .unwrap_or(actual_lines.len().min(expected_lines.len()));
+    panic!(
+        "{}: {} points instead of {}, first difference on line {}: {:?} instead of {:?}",
+        expected_path.display(),
+        actual_lines.len(),
+        expected_lines.len(),
+        line + 1,
+        actual_lines.get(line),
+        expected_lines.get(line),
+    );
+}
+
+#[test]
+fn uniform_golden() {
+    check("online/uniform", "20081023025304-0.csv", |points| uniform(points, 5));
+}
+
+#[test]
+fn opw_golden() {
+    check("online/opw", "20081023025304-0.csv", |points| opw(points, 0.0001));
+}
+
+#[test]
+fn opw_tr_golden() {
+    check("online/opw_tr", "20081023025304-0.csv", |points| opw_tr(points, 0.0003));
+}
+
+#[test]
+fn dead_reckoning_golden() {
+    check("online/dead_reckoning", "20081023025304-0.csv", |points| dead_reckoning(points, 0.006));
+}
+
+#[test]
+fn squish_golden() {
+    check("online/squish", "20081023025304-0.csv", |points| squish(points, 0.2));
+}
+
+#[test]
+fn squish_e_mu_golden() {
+    check("online/squish_e", "20081023025304-0.csv", |points| squish_e(points, 1.0, 0.0001));
+}
+
+#[test]
+fn squish_e_lambda_golden() {
+    check("online/squish_e", "20081023025304-0.txt", |points| squish_e(points, 5.0, 0.0));
+}
+
+#[test]
+fn sttrace_golden() {
+    check("online/sttrace", "20081023025304-0.csv", |points| sttrace(points, 0.2));
+}
+
+#[test]
+fn threshold_golden() {
+    check("online/threshold", "20081023025304-0.csv", |points| threshold(points, 25.0, 2, 0.8));
+}
+
+#[test]
+fn douglas_peucker_golden() {
+    check("offline/douglas_peucker", "20081023025304-0.csv", |points| douglas_peucker(points, 0.0001));
+}
+
+#[test]
+fn td_tr_golden() {
+    check("offline/td_tr", "20081023025304-0.csv", |points| td_tr(points, 0.0003));
+}
+
+#[test]
+fn visvalingam_golden() {
+    check("offline/visvalingam", "20081023025304-0.csv", |points| {
+        visvalingam(points, VwLimit::Points((0.2 * points.len() as f64) as usize))
+    });
+}
+
+#[test]
+fn buffered_visvalingam_golden() {
+    check("online/buffered_visvalingam", "20081023025304-0.csv", |points| {
+        buffered_visvalingam(points, VwLimit::Points((0.2 * points.len() as f64) as usize))
+    });
+}
@@ -0,0 +1,30 @@
+//! SQUISH-E(μ), that is λ = 1, must not drop a point its buffer has room
+//! for: with μ = 0 only points with a zero priority go.
+
+use trajcomp::evaluation::evaluate;
+use trajcomp::io::gpsreader;
+use trajcomp::online::squish_e;
+
+mod common;
+use common::{trajectory, GEOLIFE_PLT};
+
+#[test]
+fn zigzag_keeps_every_point() {
+    let points = trajectory(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0), (3.0, 1.0), (4.0, 0.0), (5.0, 1.0)]);
+    assert_eq!(squish_e(&points, 1.0, 0.0).unwrap(), points);
+}
+
+#[test]
+fn drops_only_points_on_the_way() {
+    // Points 1 and 3 lie where the segments around them put them at their time
+    let points = trajectory(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 1.0), (4.0, 0.0), (5.0, 1.0)]);
+    let kept = squish_e(&points, 1.0, 0.0).unwrap();
+    assert_eq!(kept, [points[0], points[2], points[4], points[5]]);
+}
+
+#[test]
+fn bundled_trace_has_no_sed_error() {
+    let points = gpsreader(GEOLIFE_PLT).unwrap();
+    let kept = squish_e(&points, 1.0, 0.0).unwrap();
+    assert_eq!(evaluate(&points, &kept).sed.max, 0.0);
+}